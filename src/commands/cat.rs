@@ -12,25 +12,95 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use super::reader;
+use std::io::{self, Write};
+
+#[derive(Default)]
+struct Options {
+    number: bool,
+    show_all: bool,
+    squeeze_blank: bool,
+}
 
 pub fn command(args: &[&str]) -> Result<(), String> {
-    if args.is_empty() {
-        return Err("Usage: cat [<path> [<path> [<path> ...]]]".to_string());
+    let mut options = Options::default();
+    let mut files = Vec::new();
+
+    for arg in args {
+        match *arg {
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => {
+                for flag in arg[1..].chars() {
+                    match flag {
+                        'n' => options.number = true,
+                        'A' => options.show_all = true,
+                        's' => options.squeeze_blank = true,
+                        _ => return Err(format!("invalid option -- '{}'", flag)),
+                    }
+                }
+            }
+            _ => files.push(*arg),
+        }
+    }
+    if files.is_empty() {
+        return Err("Usage: cat [-n] [-A] [-s] [<path>|- [<path>|- ...]]".to_string());
     }
-    for filename in args {
-        let file = File::open(filename)
-            .map_err(|e| format!("unable to open file '{}': {}", filename, e))?;
-        let reader = BufReader::new(file);
-        for line in reader.lines() {
-            println!(
-                "{}",
-                line.map_err(|e| format!("reading file failed: {}", e))?
-            );
+
+    let mut out = io::stdout().lock();
+    let mut line_no = 0;
+    let mut previous_blank = false;
+
+    for filename in files {
+        let mut input = reader::open(filename)?;
+
+        if !options.number && !options.show_all && !options.squeeze_blank {
+            io::copy(&mut input, &mut out)
+                .map_err(|e| format!("reading file '{}' failed: {}", filename, e))?;
+            continue;
+        }
+
+        while let Some(line) = reader::read_line(&mut input, filename)? {
+            let blank = line == b"\n";
+            if options.squeeze_blank && blank && previous_blank {
+                continue;
+            }
+            previous_blank = blank;
+
+            if options.number {
+                line_no += 1;
+                write!(out, "{:>6}\t", line_no).map_err(|e| e.to_string())?;
+            }
+            if options.show_all {
+                out.write_all(&show_nonprinting(&line))
+            } else {
+                out.write_all(&line)
+            }
+            .map_err(|e| e.to_string())?;
+        }
+    }
+
+    out.flush().map_err(|e| e.to_string())
+}
+
+// Same notation as `cat -A`: `^X` for control characters, `M-` for bytes above
+// 127, `^I` for tabs and `$` at the end of each line.
+fn show_nonprinting(line: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(line.len());
+    for &byte in line {
+        let (meta, byte) = if byte >= 0x80 {
+            (true, byte - 0x80)
+        } else {
+            (false, byte)
+        };
+        if meta {
+            result.extend_from_slice(b"M-");
+        }
+        match byte {
+            b'\n' if !meta => result.extend_from_slice(b"$\n"),
+            0..=0x1f => result.extend_from_slice(&[b'^', byte + 0x40]),
+            0x7f => result.extend_from_slice(b"^?"),
+            _ => result.push(byte),
         }
     }
-    Ok(())
+    result
 }
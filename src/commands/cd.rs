@@ -12,35 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{env, path::Path};
+use std::{
+    env, fs,
+    path::{Component, Path, PathBuf},
+};
+
+// Same limit as Linux MAXSYMLINKS, guards against symlink loops.
+const MAX_SYMLINKS: usize = 40;
 
 pub fn command(args: &[&str]) -> Result<(), String> {
     if args.len() != 1 {
         return Err("Usage: cd <directory>".to_string());
     }
-    let target = args[0];
-    let target_path = match target {
-        "." => env::current_dir().map_err(|e| format!("Unable to get current directory: {}", e))?,
-        path => {
-            let current_dir = env::current_dir()
-                .map_err(|e| format!("Unable to get current directory: {}", e))?;
-            let mut path_buf = current_dir;
-            for component in Path::new(path).components() {
-                match component {
-                    std::path::Component::ParentDir => {
-                        if !path_buf.pop() {
-                            return Err("Already at root directory".to_string());
-                        }
-                    }
-                    std::path::Component::Normal(dir) => {
-                        path_buf.push(dir);
-                    }
-                    _ => {}
-                }
-            }
-            path_buf
-        }
-    };
+    let target_path = resolve_path(Path::new(args[0]))?;
     if let Err(e) = env::set_current_dir(&target_path) {
         Err(format!(
             "Unable to change directory to '{}': {}",
@@ -51,3 +35,56 @@ pub fn command(args: &[&str]) -> Result<(), String> {
         Ok(())
     }
 }
+
+/// Walks `path` component by component starting from the current directory,
+/// replacing every symbolic link with its target, and returns the physical path.
+/// Components that do not exist are kept as they are.
+pub fn resolve_path(path: &Path) -> Result<PathBuf, String> {
+    let mut path_buf =
+        env::current_dir().map_err(|e| format!("Unable to get current directory: {}", e))?;
+    // Pending components, the next one to visit is at the end.
+    let mut pending: Vec<PathBuf> = path
+        .components()
+        .rev()
+        .map(|c| PathBuf::from(c.as_os_str()))
+        .collect();
+    let mut links_followed = 0;
+
+    while let Some(next) = pending.pop() {
+        match next.components().next() {
+            Some(Component::RootDir) => path_buf = PathBuf::from("/"),
+            Some(Component::ParentDir) => {
+                if !path_buf.pop() {
+                    return Err("Already at root directory".to_string());
+                }
+            }
+            Some(Component::Normal(dir)) => {
+                path_buf.push(dir);
+                let is_symlink = fs::symlink_metadata(&path_buf)
+                    .map(|m| m.file_type().is_symlink())
+                    .unwrap_or(false);
+                if is_symlink {
+                    links_followed += 1;
+                    if links_followed > MAX_SYMLINKS {
+                        return Err(format!(
+                            "Too many levels of symbolic links: {}",
+                            path.display()
+                        ));
+                    }
+                    let link = fs::read_link(&path_buf).map_err(|e| {
+                        format!("Unable to read link '{}': {}", path_buf.display(), e)
+                    })?;
+                    path_buf.pop();
+                    pending.extend(
+                        link.components()
+                            .rev()
+                            .map(|c| PathBuf::from(c.as_os_str())),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(path_buf)
+}
@@ -0,0 +1,536 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+use std::io::Read;
+
+const CHUNK_SIZE: usize = 4096;
+
+pub fn md5sum(args: &[&str]) -> Result<(), String> {
+    run(args, "md5sum", || Box::new(Md5::new()))
+}
+
+pub fn sha1sum(args: &[&str]) -> Result<(), String> {
+    run(args, "sha1sum", || Box::new(Sha1::new()))
+}
+
+pub fn sha256sum(args: &[&str]) -> Result<(), String> {
+    run(args, "sha256sum", || Box::new(Sha256::new()))
+}
+
+pub fn crc32(args: &[&str]) -> Result<(), String> {
+    run(args, "crc32", || Box::new(Crc32::new()))
+}
+
+pub trait Digest {
+    fn update(&mut self, data: &[u8]);
+    fn finish(self: Box<Self>) -> Vec<u8>;
+}
+
+fn run(args: &[&str], name: &str, new_digest: fn() -> Box<dyn Digest>) -> Result<(), String> {
+    let mut check = false;
+    let mut files = Vec::new();
+    for arg in args {
+        match *arg {
+            "-c" | "--check" => check = true,
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+    if files.is_empty() {
+        return Err(format!("Usage: {} [-c] <file>...", name));
+    }
+
+    if check {
+        return files
+            .iter()
+            .try_for_each(|list| check_list(list, new_digest));
+    }
+    for file in files {
+        let digest = hash_file(file, new_digest())?;
+        println!("{}  {}", to_hex(&digest), file);
+    }
+    Ok(())
+}
+
+fn hash_file(name: &str, mut digest: Box<dyn Digest>) -> Result<Vec<u8>, String> {
+    let mut input = reader::open(name)?;
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    loop {
+        let read = input
+            .read(&mut buffer)
+            .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+        if read == 0 {
+            break;
+        }
+        digest.update(&buffer[..read]);
+    }
+    Ok(digest.finish())
+}
+
+// Verifies every `<hex>  <file>` line of `list`, as produced by the same command.
+fn check_list(list: &str, new_digest: fn() -> Box<dyn Digest>) -> Result<(), String> {
+    let mut input = reader::open(list)?;
+    let mut failed = 0;
+    let mut unreadable = 0;
+
+    while let Some(line) = reader::read_line(&mut input, list)? {
+        let line = String::from_utf8_lossy(&line);
+        let line = line.trim_end_matches(['\n', '\r']);
+        if line.is_empty() {
+            continue;
+        }
+        // `*` before the name marks binary mode in coreutils lists, it changes nothing here.
+        let Some((expected, file)) = line.split_once("  ").or_else(|| line.split_once(" *")) else {
+            return Err(format!("{}: improperly formatted checksum line", list));
+        };
+
+        match hash_file(file, new_digest()) {
+            Ok(digest) if to_hex(&digest).eq_ignore_ascii_case(expected) => {
+                println!("{}: OK", file)
+            }
+            Ok(_) => {
+                println!("{}: FAILED", file);
+                failed += 1;
+            }
+            Err(e) => {
+                println!("{}: FAILED open or read ({})", file, e);
+                unreadable += 1;
+            }
+        }
+    }
+
+    if unreadable > 0 {
+        println!("WARNING: {} listed file(s) could not be read", unreadable);
+    }
+    if failed > 0 {
+        println!("WARNING: {} computed checksum(s) did NOT match", failed);
+    }
+    if failed + unreadable > 0 {
+        return Err(format!("{}: verification failed", list));
+    }
+    Ok(())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Buffers input into 64-byte blocks and applies the Merkle–Damgård padding
+/// shared by MD5, SHA-1 and SHA-256.
+struct BlockBuffer {
+    block: [u8; 64],
+    filled: usize,
+    length: u64,
+}
+
+impl BlockBuffer {
+    fn new() -> Self {
+        BlockBuffer {
+            block: [0; 64],
+            filled: 0,
+            length: 0,
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8], mut compress: impl FnMut(&[u8; 64])) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        while !data.is_empty() {
+            let take = (64 - self.filled).min(data.len());
+            self.block[self.filled..self.filled + take].copy_from_slice(&data[..take]);
+            self.filled += take;
+            data = &data[take..];
+            if self.filled == 64 {
+                compress(&self.block);
+                self.filled = 0;
+            }
+        }
+    }
+
+    // Appends 0x80, zero padding and the bit length, in little or big endian.
+    fn pad(&mut self, little_endian: bool, mut compress: impl FnMut(&[u8; 64])) {
+        let bits = self.length.wrapping_mul(8);
+        self.block[self.filled] = 0x80;
+        self.block[self.filled + 1..].fill(0);
+        if self.filled >= 56 {
+            compress(&self.block);
+            self.block.fill(0);
+        }
+        let length = if little_endian {
+            bits.to_le_bytes()
+        } else {
+            bits.to_be_bytes()
+        };
+        self.block[56..].copy_from_slice(&length);
+        compress(&self.block);
+    }
+}
+
+pub struct Md5 {
+    state: [u32; 4],
+    buffer: BlockBuffer,
+}
+
+impl Md5 {
+    const SHIFTS: [u32; 16] = [7, 12, 17, 22, 5, 9, 14, 20, 4, 11, 16, 23, 6, 10, 15, 21];
+    // K[i] = floor(abs(sin(i + 1)) * 2^32), RFC 1321 section 3.4.
+    const K: [u32; 64] = [
+        0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613,
+        0xfd469501, 0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193,
+        0xa679438e, 0x49b40821, 0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d,
+        0x02441453, 0xd8a1e681, 0xe7d3fbc8, 0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed,
+        0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a, 0xfffa3942, 0x8771f681, 0x6d9d6122,
+        0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70, 0x289b7ec6, 0xeaa127fa,
+        0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665, 0xf4292244,
+        0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+        0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb,
+        0xeb86d391,
+    ];
+
+    pub fn new() -> Self {
+        Md5 {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476],
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    fn compress(state: &mut [u32; 4], block: &[u8; 64]) {
+        let mut words = [0u32; 16];
+        for (i, chunk) in block.chunks_exact(4).enumerate() {
+            words[i] = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        let [mut a, mut b, mut c, mut d] = *state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let rotated = a
+                .wrapping_add(f)
+                .wrapping_add(Self::K[i])
+                .wrapping_add(words[g])
+                .rotate_left(Self::SHIFTS[(i / 16) * 4 + i % 4]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(rotated);
+        }
+        for (s, v) in state.iter_mut().zip([a, b, c, d]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+}
+
+impl Digest for Md5 {
+    fn update(&mut self, data: &[u8]) {
+        let state = &mut self.state;
+        self.buffer
+            .update(data, |block| Self::compress(state, block));
+    }
+
+    fn finish(mut self: Box<Self>) -> Vec<u8> {
+        let state = &mut self.state;
+        self.buffer.pad(true, |block| Self::compress(state, block));
+        self.state.iter().flat_map(|s| s.to_le_bytes()).collect()
+    }
+}
+
+pub struct Sha1 {
+    state: [u32; 5],
+    buffer: BlockBuffer,
+}
+
+impl Sha1 {
+    pub fn new() -> Self {
+        Sha1 {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    fn compress(state: &mut [u32; 5], block: &[u8; 64]) {
+        let mut w = [0u32; 80];
+        for (i, chunk) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = *state;
+        for (i, word) in w.iter().enumerate() {
+            let (f, k) = match i / 20 {
+                0 => ((b & c) | (!b & d), 0x5a827999),
+                1 => (b ^ c ^ d, 0x6ed9eba1),
+                2 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+}
+
+impl Digest for Sha1 {
+    fn update(&mut self, data: &[u8]) {
+        let state = &mut self.state;
+        self.buffer
+            .update(data, |block| Self::compress(state, block));
+    }
+
+    fn finish(mut self: Box<Self>) -> Vec<u8> {
+        let state = &mut self.state;
+        self.buffer.pad(false, |block| Self::compress(state, block));
+        self.state.iter().flat_map(|s| s.to_be_bytes()).collect()
+    }
+}
+
+pub struct Sha256 {
+    state: [u32; 8],
+    buffer: BlockBuffer,
+}
+
+impl Sha256 {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    pub fn new() -> Self {
+        Sha256 {
+            state: [
+                0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+                0x5be0cd19,
+            ],
+            buffer: BlockBuffer::new(),
+        }
+    }
+
+    fn compress(state: &mut [u32; 8], block: &[u8; 64]) {
+        let mut w = [0u32; 64];
+        for (i, chunk) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for (k, word) in Self::K.iter().zip(w) {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(*k)
+                .wrapping_add(word);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = s.wrapping_add(v);
+        }
+    }
+}
+
+impl Digest for Sha256 {
+    fn update(&mut self, data: &[u8]) {
+        let state = &mut self.state;
+        self.buffer
+            .update(data, |block| Self::compress(state, block));
+    }
+
+    fn finish(mut self: Box<Self>) -> Vec<u8> {
+        let state = &mut self.state;
+        self.buffer.pad(false, |block| Self::compress(state, block));
+        self.state.iter().flat_map(|s| s.to_be_bytes()).collect()
+    }
+}
+
+/// CRC-32 (IEEE 802.3, as used by zlib and `cksum -a crc32b`).
+pub struct Crc32 {
+    table: [u32; 256],
+    crc: u32,
+}
+
+impl Crc32 {
+    pub fn new() -> Self {
+        let mut table = [0u32; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            let mut c = i as u32;
+            for _ in 0..8 {
+                c = if c & 1 != 0 {
+                    0xedb88320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+            }
+            *entry = c;
+        }
+        Crc32 {
+            table,
+            crc: 0xffffffff,
+        }
+    }
+}
+
+impl Digest for Crc32 {
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.crc = self.table[((self.crc ^ byte as u32) & 0xff) as usize] ^ (self.crc >> 8);
+        }
+    }
+
+    fn finish(self: Box<Self>) -> Vec<u8> {
+        (!self.crc).to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(mut digest: Box<dyn Digest>, data: &[u8]) -> String {
+        digest.update(data);
+        to_hex(&digest.finish())
+    }
+
+    // Fed in uneven pieces so block boundaries fall inside updates.
+    fn digest_split(mut digest: Box<dyn Digest>, data: &[u8]) -> String {
+        for chunk in data.chunks(37) {
+            digest.update(chunk);
+        }
+        to_hex(&digest.finish())
+    }
+
+    #[test]
+    fn md5_rfc1321_vectors() {
+        let vectors: [(&[u8], &str); 7] = [
+            (b"", "d41d8cd98f00b204e9800998ecf8427e"),
+            (b"a", "0cc175b9c0f1b6a831c399e269772661"),
+            (b"abc", "900150983cd24fb0d6963f7d28e17f72"),
+            (b"message digest", "f96b697d7cb7938d525a2f31aaf161d0"),
+            (
+                b"abcdefghijklmnopqrstuvwxyz",
+                "c3fcd3d76192e4007dfb496cca67e13b",
+            ),
+            (
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+                "d174ab98d277d9f5a5611c2c9f419d9f",
+            ),
+            (
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+                "57edf4a22be3c955ac49da2e2107b67a",
+            ),
+        ];
+        for (data, expected) in vectors {
+            assert_eq!(digest(Box::new(Md5::new()), data), expected);
+            assert_eq!(digest_split(Box::new(Md5::new()), data), expected);
+        }
+    }
+
+    #[test]
+    fn sha1_fips180_vectors() {
+        let vectors: [(&[u8], &str); 3] = [
+            (b"", "da39a3ee5e6b4b0d3255bfef95601890afd80709"),
+            (b"abc", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "84983e441c3bd26ebaae4aa1f95129e5e54670f1",
+            ),
+        ];
+        for (data, expected) in vectors {
+            assert_eq!(digest(Box::new(Sha1::new()), data), expected);
+            assert_eq!(digest_split(Box::new(Sha1::new()), data), expected);
+        }
+        assert_eq!(
+            digest_split(Box::new(Sha1::new()), &[b'a'; 1_000_000]),
+            "34aa973cd4c4daa4f61eeb2bdbad27316534016f"
+        );
+    }
+
+    #[test]
+    fn sha256_fips180_vectors() {
+        let vectors: [(&[u8], &str); 3] = [
+            (
+                b"",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            (
+                b"abc",
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+            ),
+        ];
+        for (data, expected) in vectors {
+            assert_eq!(digest(Box::new(Sha256::new()), data), expected);
+            assert_eq!(digest_split(Box::new(Sha256::new()), data), expected);
+        }
+        assert_eq!(
+            digest_split(Box::new(Sha256::new()), &[b'a'; 1_000_000]),
+            "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+        );
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(digest(Box::new(Crc32::new()), b""), "00000000");
+        assert_eq!(digest(Box::new(Crc32::new()), b"123456789"), "cbf43926");
+        assert_eq!(
+            digest(
+                Box::new(Crc32::new()),
+                b"The quick brown fox jumps over the lazy dog"
+            ),
+            "414fa339"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::chown::{change_owner, parse_args, parse_id};
+use std::path::Path;
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let (recursive, operands) = parse_args(args)?;
+    if operands.len() < 2 {
+        return Err("Usage: chgrp [-R] <gid> <file>...".to_string());
+    }
+
+    let gid = parse_id(operands[0])?.ok_or("missing group id")?;
+    for file in &operands[1..] {
+        change_owner(Path::new(file), None, Some(gid), recursive)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{mkdir::parse_mode, walk::Walk};
+use std::{
+    fs,
+    os::unix::fs::{MetadataExt, PermissionsExt},
+    path::Path,
+};
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut recursive = false;
+    let mut verbose = false;
+    let mut operands = Vec::new();
+
+    for arg in args {
+        match *arg {
+            "-R" | "--recursive" => recursive = true,
+            "-v" | "--verbose" => verbose = true,
+            // Symbolic modes such as `-w` look like options, treat them as operands.
+            _ if arg.starts_with('-') && parse_mode(arg, 0).is_err() => {
+                return Err(format!("invalid option -- '{}'", arg))
+            }
+            _ => operands.push(*arg),
+        }
+    }
+
+    if operands.len() < 2 {
+        return Err("Usage: chmod [-R] [-v] <mode[,mode]...|octal mode> <file>...".to_string());
+    }
+
+    let mode = operands[0];
+    let walk = Walk::new().max_depth(if recursive { usize::MAX } else { 0 });
+    for file in &operands[1..] {
+        walk.run(Path::new(file), &mut |entry| {
+            if entry.depth > 0 {
+                // Links inside the tree are skipped, their own mode means nothing and
+                // set_permissions would change whatever they point to.
+                if entry.metadata.file_type().is_symlink() {
+                    return Ok(());
+                }
+                return change_mode(&entry.path, &entry.metadata, mode, verbose);
+            }
+            // An operand that is a link names its target, so start from the target's mode.
+            let metadata = fs::metadata(&entry.path)
+                .map_err(|e| format!("cannot access '{}': {}", entry.path.display(), e))?;
+            change_mode(&entry.path, &metadata, mode, verbose)
+        })?;
+    }
+
+    Ok(())
+}
+
+fn change_mode(
+    path: &Path,
+    metadata: &fs::Metadata,
+    mode_str: &str,
+    verbose: bool,
+) -> Result<(), String> {
+    let old_mode = metadata.mode();
+
+    if let Some(new_mode) = parse_mode(mode_str, old_mode)? {
+        fs::set_permissions(path, fs::Permissions::from_mode(new_mode))
+            .map_err(|e| format!("changing permissions of '{}': {}", path.display(), e))?;
+        if verbose {
+            println!(
+                "mode of '{}' changed from {:04o} to {:04o}",
+                path.display(),
+                old_mode & 0o7777,
+                new_mode
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::walk::Walk;
+use std::{os::unix::fs as unix_fs, path::Path};
+
+// There is no user database on the device, so owners are numeric ids only.
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let (recursive, operands) = parse_args(args)?;
+    if operands.len() < 2 {
+        return Err("Usage: chown [-R] <uid>[:<gid>] <file>...".to_string());
+    }
+
+    let (uid, gid) = match operands[0].split_once([':', '.']) {
+        Some((uid, gid)) => (parse_id(uid)?, parse_id(gid)?),
+        None => (parse_id(operands[0])?, None),
+    };
+
+    for file in &operands[1..] {
+        change_owner(Path::new(file), uid, gid, recursive)?;
+    }
+    Ok(())
+}
+
+pub(super) fn parse_args<'a>(args: &[&'a str]) -> Result<(bool, Vec<&'a str>), String> {
+    let mut recursive = false;
+    let mut operands = Vec::new();
+    for arg in args {
+        match *arg {
+            "-R" | "--recursive" => recursive = true,
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => operands.push(*arg),
+        }
+    }
+    Ok((recursive, operands))
+}
+
+pub(super) fn parse_id(id: &str) -> Result<Option<u32>, String> {
+    if id.is_empty() {
+        return Ok(None);
+    }
+    id.parse()
+        .map(Some)
+        .map_err(|_| format!("invalid id '{}'", id))
+}
+
+pub(super) fn change_owner(
+    path: &Path,
+    uid: Option<u32>,
+    gid: Option<u32>,
+    recursive: bool,
+) -> Result<(), String> {
+    if !recursive {
+        return unix_fs::chown(path, uid, gid)
+            .map_err(|e| format!("changing ownership of '{}': {}", path.display(), e));
+    }
+    // Links met inside the tree are changed themselves, not their targets.
+    Walk::new().run(path, &mut |entry| {
+        unix_fs::lchown(&entry.path, uid, gid)
+            .map_err(|e| format!("changing ownership of '{}': {}", entry.path.display(), e))
+    })
+}
@@ -12,58 +12,126 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::File;
+use super::reader::{self, open_at, parse_offset};
 use std::io::Read;
 
+#[derive(PartialEq)]
+enum Mode {
+    First,
+    List,
+    Silent,
+}
+
+// Returns Ok only when the files are identical, so callers can branch on the result.
 pub fn command(args: &[&str]) -> Result<(), String> {
-    if args.len() != 2 {
-        return Err("Usage: cmp <path1> <path2>".to_string());
+    let mut mode = Mode::First;
+    let mut limit = None;
+    let mut skips = Vec::new();
+    let mut operands = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-l" | "--verbose" => mode = Mode::List,
+            "-s" | "--silent" | "--quiet" => mode = Mode::Silent,
+            "-n" | "--bytes" => {
+                limit = Some(parse_offset(
+                    iter.next().ok_or("option requires an argument -- 'n'")?,
+                )?)
+            }
+            "-i" | "--ignore-initial" => {
+                let value = iter.next().ok_or("option requires an argument -- 'i'")?;
+                skips = match value.split_once(':') {
+                    Some((skip1, skip2)) => vec![parse_offset(skip1)?, parse_offset(skip2)?],
+                    None => vec![parse_offset(value)?; 2],
+                };
+            }
+            reader::STDIN => operands.push(*arg),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => operands.push(*arg),
+        }
     }
 
-    let mut file1 =
-        File::open(args[0]).map_err(|e| format!("Failed to open '{}': {}", args[0], e))?;
-    let mut file2 =
-        File::open(args[1]).map_err(|e| format!("Failed to open '{}': {}", args[1], e))?;
+    if !(2..=4).contains(&operands.len()) {
+        return Err(
+            "Usage: cmp [-l | -s] [-n <limit>] [-i <skip1>[:<skip2>]] <path1> <path2> [<skip1> [<skip2>]]"
+                .to_string(),
+        );
+    }
+    for skip in &operands[2..] {
+        if skips.len() == 2 {
+            skips.clear();
+        }
+        skips.push(parse_offset(skip)?);
+    }
+    let skip1 = skips.first().copied().unwrap_or(0);
+    let skip2 = skips.get(1).copied().unwrap_or(0);
 
-    let mut diff_bytes = 0;
-    let mut total_size_diff = 0;
+    let (name1, name2) = (operands[0], operands[1]);
+    let limit = limit.unwrap_or(u64::MAX);
+    let mut file1 = open_at(name1, skip1)?.take(limit);
+    let mut file2 = open_at(name2, skip2)?.take(limit);
 
     let (mut buf1, mut buf2) = ([0u8; 512], [0u8; 512]);
+    let mut byte = 0u64;
+    let mut line = 1u64;
+    let mut last_was_newline = false;
+    let mut differ = false;
 
     loop {
-        let n1 = file1.read(&mut buf1).map_err(|e| e.to_string())?;
-        let n2 = file2.read(&mut buf2).map_err(|e| e.to_string())?;
+        let n1 = reader::read_full(&mut file1, &mut buf1)
+            .map_err(|e| format!("reading '{}' failed: {}", name1, e))?;
+        let n2 = reader::read_full(&mut file2, &mut buf2)
+            .map_err(|e| format!("reading '{}' failed: {}", name2, e))?;
 
-        if n1 == 0 && n2 == 0 {
-            break;
+        for (b1, b2) in buf1[..n1].iter().zip(&buf2[..n2]) {
+            byte += 1;
+            if b1 != b2 {
+                differ = true;
+                match mode {
+                    Mode::First => {
+                        return Err(format!(
+                            "{} {} differ: char {}, line {}",
+                            name1, name2, byte, line
+                        ))
+                    }
+                    Mode::Silent => return Err(String::new()),
+                    Mode::List => println!("{} {:o} {:o}", byte, b1, b2),
+                }
+            }
+            last_was_newline = *b1 == b'\n';
+            if last_was_newline {
+                line += 1;
+            }
         }
 
         if n1 != n2 {
-            total_size_diff = if n1 > n2 {
-                file1.read_to_end(&mut Vec::new()).unwrap_or_default() as i64 - n2 as i64
+            let shorter = if n1 < n2 { name1 } else { name2 };
+            if mode == Mode::Silent {
+                return Err(String::new());
+            }
+            if byte == 0 {
+                return Err(format!("EOF on {} which is empty", shorter));
+            }
+            // GNU cmp reports the line of the last byte read, not the next one.
+            let last_line = if mode == Mode::List {
+                String::new()
             } else {
-                file2.read_to_end(&mut Vec::new()).unwrap_or_default() as i64 - n1 as i64
+                format!(", line {}", line - u64::from(last_was_newline))
             };
-            diff_bytes += (n1 as i64 - n2 as i64).unsigned_abs() as usize;
-            break;
+            return Err(format!(
+                "EOF on {} after byte {}{}",
+                shorter, byte, last_line
+            ));
         }
-
-        for i in 0..n1 {
-            if buf1[i] != buf2[i] {
-                diff_bytes += 1;
-            }
+        if n1 == 0 {
+            break;
         }
     }
 
-    match (diff_bytes, total_size_diff) {
-        (0, 0) => println!("Files are identical"),
-        (_, 0) => println!("Found {} differing bytes", diff_bytes),
-        (bytes, diff) => println!(
-            "Files differ in length by {} bytes\nAdditionally found {} differing bytes",
-            diff.abs(),
-            bytes
-        ),
+    if differ {
+        // The differences were already listed by -l.
+        return Err(String::new());
     }
-
     Ok(())
 }
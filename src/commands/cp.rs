@@ -12,12 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::reader;
 use std::{
     fs::File,
     io::{Read, Write},
     path::Path,
 };
 
+/// Block layout of a copy, cp uses the defaults and dd sets every field.
+pub struct Blocks {
+    pub size: usize,
+    // Number of input blocks to copy, all of them when None.
+    pub count: Option<u64>,
+    // Pad short input blocks with zeros up to `size`.
+    pub pad: bool,
+}
+
+impl Default for Blocks {
+    fn default() -> Self {
+        Blocks {
+            size: 512,
+            count: None,
+            pad: false,
+        }
+    }
+}
+
+/// Blocks are counted on both sides, padding turns short input blocks into full output ones.
+#[derive(Default)]
+pub struct Copied {
+    pub full_in: u64,
+    pub partial_in: u64,
+    pub full_out: u64,
+    pub partial_out: u64,
+    pub bytes: u64,
+}
+
 pub fn command(args: &[&str]) -> Result<(), String> {
     if args.len() != 2 {
         return Err("Usage: cp <source file> <destination file/dir>".to_string());
@@ -59,25 +89,66 @@ fn copy_file_using_read_write(src: &Path, dst: &Path) -> Result<(), String> {
         )
     })?;
 
-    let mut buffer = vec![0; 512];
+    copy_blocks(
+        &mut src_file,
+        &src.display().to_string(),
+        &mut dst_file,
+        &dst.display().to_string(),
+        &Blocks::default(),
+        &mut |_| {},
+    )?;
+
+    dst_file
+        .flush()
+        .map_err(|e| format!("Failed to flush '{}': {}", dst.display(), e))?;
+
+    Ok(())
+}
 
-    loop {
-        let bytes_read = src_file
-            .read(&mut buffer)
-            .map_err(|e| format!("Failed to read from '{}': {}", src.display(), e))?;
+/// Copies `src` to `dst` one block at a time. Each block is filled completely
+/// before it is written, so only the last one can be short. `progress` is
+/// called after every block.
+pub fn copy_blocks(
+    src: &mut dyn Read,
+    src_name: &str,
+    dst: &mut dyn Write,
+    dst_name: &str,
+    blocks: &Blocks,
+    progress: &mut dyn FnMut(&Copied),
+) -> Result<Copied, String> {
+    let mut buffer = vec![0; blocks.size];
+    let mut copied = Copied::default();
+
+    while blocks
+        .count
+        .is_none_or(|count| copied.full_in + copied.partial_in < count)
+    {
+        let mut bytes_read = reader::read_full(src, &mut buffer)
+            .map_err(|e| format!("Failed to read from '{}': {}", src_name, e))?;
 
         if bytes_read == 0 {
             break;
         }
+        if bytes_read == buffer.len() {
+            copied.full_in += 1;
+        } else {
+            copied.partial_in += 1;
+            if blocks.pad {
+                buffer[bytes_read..].fill(0);
+                bytes_read = buffer.len();
+            }
+        }
+        if bytes_read == buffer.len() {
+            copied.full_out += 1;
+        } else {
+            copied.partial_out += 1;
+        }
 
-        dst_file
-            .write_all(&buffer[..bytes_read])
-            .map_err(|e| format!("Failed to write to '{}': {}", dst.display(), e))?;
+        dst.write_all(&buffer[..bytes_read])
+            .map_err(|e| format!("Failed to write to '{}': {}", dst_name, e))?;
+        copied.bytes += bytes_read as u64;
+        progress(&copied);
     }
 
-    dst_file
-        .flush()
-        .map_err(|e| format!("Failed to flush '{}': {}", dst.display(), e))?;
-
-    Ok(())
+    Ok(copied)
 }
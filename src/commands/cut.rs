@@ -0,0 +1,99 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut delimiter = '\t';
+    let mut fields = None;
+    let mut only_delimited = false;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-d" | "--delimiter" => {
+                let value = iter.next().ok_or("option requires an argument -- 'd'")?;
+                let mut chars = value.chars();
+                delimiter = match (chars.next(), chars.next()) {
+                    (Some(c), None) => c,
+                    _ => return Err("the delimiter must be a single character".to_string()),
+                };
+            }
+            "-f" | "--fields" => {
+                fields = Some(parse_list(
+                    iter.next().ok_or("option requires an argument -- 'f'")?,
+                )?)
+            }
+            "-s" | "--only-delimited" => only_delimited = true,
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+
+    let fields =
+        fields.ok_or("Usage: cut -f <list> [-d <delimiter>] [-s] [<file>...]".to_string())?;
+
+    for name in reader::inputs(&files) {
+        let mut input = reader::open(name)?;
+        while let Some(line) = reader::read_line(&mut input, name)? {
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches('\n');
+            if !line.contains(delimiter) {
+                // Lines without any delimiter are passed through unless -s is given.
+                if !only_delimited {
+                    println!("{}", line);
+                }
+                continue;
+            }
+            let selected: Vec<&str> = line
+                .split(delimiter)
+                .zip(1..)
+                .filter(|(_, n)| fields.iter().any(|&(lo, hi)| lo <= *n && *n <= hi))
+                .map(|(field, _)| field)
+                .collect();
+            println!("{}", selected.join(&delimiter.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a list such as `1,3-5,7-` into inclusive 1-based ranges.
+fn parse_list(list: &str) -> Result<Vec<(usize, usize)>, String> {
+    let invalid = || format!("invalid field list '{}'", list);
+    let parse = |n: &str| {
+        n.parse::<usize>()
+            .ok()
+            .filter(|n| *n > 0)
+            .ok_or_else(invalid)
+    };
+
+    list.split(',')
+        .map(|range| match range.split_once('-') {
+            Some(("", "")) => Err(invalid()),
+            Some(("", hi)) => Ok((1, parse(hi)?)),
+            Some((lo, "")) => Ok((parse(lo)?, usize::MAX)),
+            Some((lo, hi)) => {
+                let (lo, hi) = (parse(lo)?, parse(hi)?);
+                if hi < lo {
+                    return Err(invalid());
+                }
+                Ok((lo, hi))
+            }
+            None => parse(range).map(|n| (n, n)),
+        })
+        .collect()
+}
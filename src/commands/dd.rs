@@ -0,0 +1,185 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    cp::{copy_blocks, Blocks, Copied},
+    ls::format_size,
+    reader::{self, open_at},
+    truncate::parse_size,
+};
+use std::{
+    fs::OpenOptions,
+    io::{self, Seek, SeekFrom, Write},
+    time::{Duration, Instant},
+};
+
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(PartialEq)]
+enum Status {
+    Default,
+    Progress,
+    NoTransfer,
+    None,
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut input = reader::STDIN;
+    let mut output = None;
+    let mut blocks = Blocks::default();
+    let mut skip = 0;
+    let mut seek = 0;
+    let mut truncate = true;
+    let mut fsync = false;
+    let mut status = Status::Default;
+
+    for arg in args {
+        let Some((key, value)) = arg.split_once('=') else {
+            return Err(format!("unrecognized operand '{}'", arg));
+        };
+        match key {
+            "if" => input = value,
+            "of" => output = Some(value),
+            "bs" => {
+                blocks.size = usize::try_from(parse_number(value)?)
+                    .ok()
+                    .filter(|size| *size > 0)
+                    .ok_or(format!("invalid block size '{}'", value))?
+            }
+            "count" => blocks.count = Some(parse_number(value)?),
+            "skip" => skip = parse_number(value)?,
+            "seek" => seek = parse_number(value)?,
+            "conv" => {
+                for conversion in value.split(',') {
+                    match conversion {
+                        "notrunc" => truncate = false,
+                        "sync" => blocks.pad = true,
+                        "fsync" => fsync = true,
+                        _ => return Err(format!("invalid conversion '{}'", conversion)),
+                    }
+                }
+            }
+            "status" => {
+                status = match value {
+                    "progress" => Status::Progress,
+                    "noxfer" => Status::NoTransfer,
+                    "none" => Status::None,
+                    _ => return Err(format!("invalid status level '{}'", value)),
+                }
+            }
+            _ => return Err(format!("unrecognized operand '{}'", arg)),
+        }
+    }
+
+    let block_size = blocks.size as u64;
+    let skip_offset = skip
+        .checked_mul(block_size)
+        .ok_or("skip offset too large")?;
+    let seek_offset = seek
+        .checked_mul(block_size)
+        .ok_or("seek offset too large")?;
+    let mut src = open_at(input, skip_offset)?;
+    let mut file = None;
+    let mut stdout = io::stdout().lock();
+    let dst: &mut dyn Write = match output {
+        Some(path) => {
+            let mut opened = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(path)
+                .map_err(|e| format!("failed to open '{}': {}", path, e))?;
+            // Like dd, only what lies past the seek offset is dropped; block
+            // devices cannot be resized, so that step is skipped for them.
+            if truncate && opened.metadata().is_ok_and(|m| m.is_file()) {
+                opened
+                    .set_len(seek_offset)
+                    .map_err(|e| format!("failed to truncate '{}': {}", path, e))?;
+            }
+            opened
+                .seek(SeekFrom::Start(seek_offset))
+                .map_err(|e| format!("failed to seek in '{}': {}", path, e))?;
+            file.insert(opened)
+        }
+        None => &mut stdout,
+    };
+
+    let start = Instant::now();
+    let mut last_report = start;
+    let copied = copy_blocks(
+        &mut src,
+        input,
+        dst,
+        output.unwrap_or("standard output"),
+        &blocks,
+        &mut |copied| {
+            if status == Status::Progress && last_report.elapsed() >= PROGRESS_INTERVAL {
+                last_report = Instant::now();
+                eprint!("\r{}", transfer_summary(copied, start.elapsed()));
+            }
+        },
+    )?;
+
+    dst.flush().map_err(|e| e.to_string())?;
+    if let Some(file) = file.filter(|_| fsync) {
+        file.sync_all()
+            .map_err(|e| format!("fsync failed: {}", e))?;
+    }
+
+    if status == Status::Progress && last_report != start {
+        eprintln!();
+    }
+    if status != Status::None {
+        eprintln!("{}+{} records in", copied.full_in, copied.partial_in);
+        eprintln!("{}+{} records out", copied.full_out, copied.partial_out);
+    }
+    if status == Status::Default || status == Status::Progress {
+        eprintln!("{}", transfer_summary(&copied, start.elapsed()));
+    }
+    Ok(())
+}
+
+fn transfer_summary(copied: &Copied, elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs_f64();
+    let rate = if seconds > 0.0 {
+        format!("{}/s", format_size((copied.bytes as f64 / seconds) as u64))
+    } else {
+        "Infinity B/s".to_string()
+    };
+    format!(
+        "{} bytes ({}) copied, {:.6} s, {}",
+        copied.bytes,
+        format_size(copied.bytes),
+        seconds,
+        rate
+    )
+}
+
+// dd adds `c` (1), `w` (2) and `b` (512) to the usual units, and `x` products such as `2x512`.
+fn parse_number(value: &str) -> Result<u64, String> {
+    if let Some((left, right)) = value.split_once('x') {
+        return parse_number(left)?
+            .checked_mul(parse_number(right)?)
+            .ok_or(format!("number '{}' is too large", value));
+    }
+    let (number, unit) = match value.char_indices().last() {
+        Some((i, 'c')) => (&value[..i], 1),
+        Some((i, 'w')) => (&value[..i], 2),
+        Some((i, 'b')) => (&value[..i], 512),
+        _ => (value, 1),
+    };
+    parse_size(number)?
+        .checked_mul(unit)
+        .ok_or(format!("number '{}' is too large", value))
+}
@@ -0,0 +1,138 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    cd::resolve_path,
+    ls::format_size,
+    mount::{read_mounts, MountEntry},
+};
+use librs::direct;
+use std::{ffi::CString, mem, path::Path};
+
+struct Usage {
+    size: u64,
+    used: u64,
+    available: u64,
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut human = false;
+    let mut show_type = false;
+    let mut paths = Vec::new();
+
+    for arg in args {
+        match *arg {
+            "-h" | "--human-readable" => human = true,
+            "-T" | "--print-type" => show_type = true,
+            "-hT" | "-Th" => {
+                human = true;
+                show_type = true;
+            }
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => paths.push(*arg),
+        }
+    }
+
+    let mounts = read_mounts()?;
+    // Only the mount holding each path, or every mount without operands.
+    let selected: Vec<&MountEntry> = if paths.is_empty() {
+        mounts.iter().collect()
+    } else {
+        paths
+            .iter()
+            .map(|path| mount_of(&mounts, path))
+            .collect::<Result<_, _>>()?
+    };
+
+    print!("{:<20}", "Filesystem");
+    if show_type {
+        print!(" {:<10}", "Type");
+    }
+    println!(
+        " {:>10} {:>10} {:>10} {:>5} Mounted on",
+        if human { "Size" } else { "1K-blocks" },
+        "Used",
+        "Available",
+        "Use%"
+    );
+
+    for mount in selected {
+        let usage = match statfs(&mount.target) {
+            Ok(usage) => usage,
+            Err(e) => {
+                println!("df: {}", e);
+                continue;
+            }
+        };
+        let format = |bytes: u64| {
+            if human {
+                format_size(bytes)
+            } else {
+                bytes.div_ceil(1024).to_string()
+            }
+        };
+        // Usage is relative to what non-root users can reach, like df does.
+        let percent = match usage.used + usage.available {
+            0 => "-".to_string(),
+            total => format!("{}%", (usage.used * 100).div_ceil(total)),
+        };
+
+        print!("{:<20}", mount.source);
+        if show_type {
+            print!(" {:<10}", mount.fs_type);
+        }
+        println!(
+            " {:>10} {:>10} {:>10} {:>5} {}",
+            format(usage.size),
+            format(usage.used),
+            format(usage.available),
+            percent,
+            mount.target
+        );
+    }
+    Ok(())
+}
+
+// The mount a path lives on is the one with the longest matching target.
+fn mount_of<'a>(mounts: &'a [MountEntry], path: &str) -> Result<&'a MountEntry, String> {
+    let path = resolve_path(Path::new(path))?;
+    if !path.exists() {
+        return Err(format!("'{}': No such file or directory", path.display()));
+    }
+    mounts
+        .iter()
+        .filter(|mount| path.starts_with(&mount.target))
+        .max_by_key(|mount| mount.target.len())
+        .ok_or(format!("'{}': no file system found", path.display()))
+}
+
+// std not support, call librs
+fn statfs(target: &str) -> Result<Usage, String> {
+    let path = CString::new(target).map_err(|e| e.to_string())?;
+    let mut stat: libc::statfs = unsafe { mem::zeroed() };
+    let result = unsafe { direct::statfs(path.as_ptr(), &mut stat) };
+    if result != 0 {
+        return Err(format!(
+            "cannot read file system information for '{}' (error code: {})",
+            target, result
+        ));
+    }
+
+    let block_size = stat.f_bsize as u64;
+    Ok(Usage {
+        size: (stat.f_blocks as u64).saturating_mul(block_size),
+        used: (stat.f_blocks.saturating_sub(stat.f_bfree) as u64).saturating_mul(block_size),
+        available: (stat.f_bavail as u64).saturating_mul(block_size),
+    })
+}
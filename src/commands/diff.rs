@@ -0,0 +1,396 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::stat::format_timestamp;
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    os::unix::fs::MetadataExt,
+};
+
+const DEFAULT_CONTEXT: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// One step of the edit script, with the line indices in both files at that point.
+#[derive(Clone, Copy)]
+struct Op {
+    kind: Kind,
+    a: usize,
+    b: usize,
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut unified = None;
+    let mut brief = false;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-u" => unified = Some(DEFAULT_CONTEXT),
+            "-U" | "--unified" => {
+                let value = iter.next().ok_or("option requires an argument -- 'U'")?;
+                unified = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid context length '{}'", value))?,
+                );
+            }
+            "-q" | "--brief" => brief = true,
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+    if files.len() != 2 {
+        return Err("Usage: diff [-q] [-u | -U <lines>] <file1> <file2>".to_string());
+    }
+
+    let old = fs::read(files[0]).map_err(|e| format!("Failed to open '{}': {}", files[0], e))?;
+    let new = fs::read(files[1]).map_err(|e| format!("Failed to open '{}': {}", files[1], e))?;
+    if old == new {
+        return Ok(());
+    }
+    // Differences fail silently, like cmp, so scripts can test the result.
+    if brief {
+        println!("Files {} and {} differ", files[0], files[1]);
+        return Err(String::new());
+    }
+
+    let old_lines = split_lines(&old);
+    let new_lines = split_lines(&new);
+    let ops = diff_lines(&old_lines, &new_lines);
+
+    let mut out = io::stdout().lock();
+    match unified {
+        Some(context) => {
+            write_header(&mut out, "---", files[0])?;
+            write_header(&mut out, "+++", files[1])?;
+            write_unified(&mut out, &ops, &old_lines, &new_lines, context)
+        }
+        None => write_normal(&mut out, &ops, &old_lines, &new_lines),
+    }
+    .map_err(|e| e.to_string())?;
+    out.flush().map_err(|e| e.to_string())?;
+    Err(String::new())
+}
+
+// Lines keep their trailing `\n`, so a missing final newline is itself a difference.
+fn split_lines(data: &[u8]) -> Vec<&[u8]> {
+    data.split_inclusive(|&b| b == b'\n').collect()
+}
+
+fn diff_lines(old: &[&[u8]], new: &[&[u8]]) -> Vec<Op> {
+    // Compare small integers instead of byte strings in the inner loops.
+    let mut ids = HashMap::new();
+    let mut intern = |line: &[u8]| {
+        let next = ids.len();
+        *ids.entry(line.to_vec()).or_insert(next)
+    };
+    let a: Vec<usize> = old.iter().map(|line| intern(line)).collect();
+    let b: Vec<usize> = new.iter().map(|line| intern(line)).collect();
+
+    let mut path = Vec::new();
+    let myers = Myers { a: &a, b: &b };
+    myers.find_path(0, 0, a.len(), b.len(), &mut path);
+    if path.first() != Some(&(0, 0)) {
+        path.insert(0, (0, 0));
+    }
+    path.push((a.len(), b.len()));
+
+    let mut ops = Vec::new();
+    for pair in path.windows(2) {
+        let (mut x, mut y) = pair[0];
+        let (x2, y2) = pair[1];
+        myers.walk_diagonal(&mut x, &mut y, x2, y2, &mut ops);
+        if x2 - x < y2 - y {
+            ops.push(Op {
+                kind: Kind::Insert,
+                a: x,
+                b: y,
+            });
+            y += 1;
+        } else if x2 - x > y2 - y {
+            ops.push(Op {
+                kind: Kind::Delete,
+                a: x,
+                b: y,
+            });
+            x += 1;
+        }
+        myers.walk_diagonal(&mut x, &mut y, x2, y2, &mut ops);
+    }
+    ops
+}
+
+/// Myers' O(ND) diff in linear space: each level finds the middle snake of the
+/// remaining box and recurses on both halves, so memory stays O(N + M).
+struct Myers<'a> {
+    a: &'a [usize],
+    b: &'a [usize],
+}
+
+type Snake = ((usize, usize), (usize, usize));
+
+impl Myers<'_> {
+    fn find_path(
+        &self,
+        left: usize,
+        top: usize,
+        right: usize,
+        bottom: usize,
+        path: &mut Vec<(usize, usize)>,
+    ) {
+        let Some((start, finish)) = self.middle_snake(left, top, right, bottom) else {
+            return;
+        };
+
+        let before = path.len();
+        self.find_path(left, top, start.0, start.1, path);
+        if path.len() == before {
+            path.push(start);
+        }
+        let middle = path.len();
+        self.find_path(finish.0, finish.1, right, bottom, path);
+        if path.len() == middle {
+            path.push(finish);
+        }
+    }
+
+    fn middle_snake(&self, left: usize, top: usize, right: usize, bottom: usize) -> Option<Snake> {
+        let width = (right - left) as isize;
+        let height = (bottom - top) as isize;
+        let size = width + height;
+        if size == 0 {
+            return None;
+        }
+        let delta = width - height;
+        let max = (size + 1) / 2;
+        let offset = max + 1;
+        let mut forward = vec![0isize; (2 * max + 3) as usize];
+        let mut backward = vec![0isize; (2 * max + 3) as usize];
+        forward[(1 + offset) as usize] = left as isize;
+        backward[(1 + offset) as usize] = bottom as isize;
+        let (left, top, right, bottom) =
+            (left as isize, top as isize, right as isize, bottom as isize);
+
+        for d in 0..=max {
+            // Forward search from the top-left corner.
+            for k in (-d..=d).rev().step_by(2) {
+                let c = k - delta;
+                let idx = |k: isize| (k + offset) as usize;
+                let (px, mut x) =
+                    if k == -d || (k != d && forward[idx(k - 1)] < forward[idx(k + 1)]) {
+                        (forward[idx(k + 1)], forward[idx(k + 1)])
+                    } else {
+                        (forward[idx(k - 1)], forward[idx(k - 1)] + 1)
+                    };
+                let mut y = top + (x - left) - k;
+                let py = if d == 0 || x != px { y } else { y - 1 };
+                while x < right && y < bottom && self.a[x as usize] == self.b[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                forward[idx(k)] = x;
+                if delta % 2 != 0 && (-(d - 1)..=(d - 1)).contains(&c) && y >= backward[idx(c)] {
+                    return Some(((px as usize, py as usize), (x as usize, y as usize)));
+                }
+            }
+
+            // Backward search from the bottom-right corner.
+            for c in (-d..=d).rev().step_by(2) {
+                let k = c + delta;
+                let idx = |c: isize| (c + offset) as usize;
+                let (py, mut y) =
+                    if c == -d || (c != d && backward[idx(c - 1)] > backward[idx(c + 1)]) {
+                        (backward[idx(c + 1)], backward[idx(c + 1)])
+                    } else {
+                        (backward[idx(c - 1)], backward[idx(c - 1)] - 1)
+                    };
+                let mut x = left + (y - top) + k;
+                let px = if d == 0 || y != py { x } else { x + 1 };
+                while x > left && y > top && self.a[(x - 1) as usize] == self.b[(y - 1) as usize] {
+                    x -= 1;
+                    y -= 1;
+                }
+                backward[idx(c)] = y;
+                if delta % 2 == 0 && (-d..=d).contains(&k) && x <= forward[idx(k)] {
+                    return Some(((x as usize, y as usize), (px as usize, py as usize)));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn walk_diagonal(&self, x: &mut usize, y: &mut usize, x2: usize, y2: usize, ops: &mut Vec<Op>) {
+        while *x < x2 && *y < y2 && self.a[*x] == self.b[*y] {
+            ops.push(Op {
+                kind: Kind::Equal,
+                a: *x,
+                b: *y,
+            });
+            *x += 1;
+            *y += 1;
+        }
+    }
+}
+
+fn write_header(out: &mut dyn Write, marker: &str, file: &str) -> Result<(), String> {
+    let metadata = fs::metadata(file).map_err(|e| format!("cannot stat '{}': {}", file, e))?;
+    writeln!(
+        out,
+        "{} {}\t{}",
+        marker,
+        file,
+        format_timestamp(metadata.mtime(), metadata.mtime_nsec())
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn write_line(out: &mut dyn Write, prefix: &str, line: &[u8]) -> io::Result<()> {
+    out.write_all(prefix.as_bytes())?;
+    out.write_all(line)?;
+    if !line.ends_with(b"\n") {
+        out.write_all(b"\n\\ No newline at end of file\n")?;
+    }
+    Ok(())
+}
+
+// 1-based `start,count` as used in hunk headers; an empty range names the line before it.
+fn unified_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+fn write_unified(
+    out: &mut dyn Write,
+    ops: &[Op],
+    old: &[&[u8]],
+    new: &[&[u8]],
+    context: usize,
+) -> io::Result<()> {
+    let mut i = 0;
+    while i < ops.len() {
+        if ops[i].kind == Kind::Equal {
+            i += 1;
+            continue;
+        }
+
+        // Grow the hunk while the unchanged gap to the next change fits in 2 * context.
+        let start = i.saturating_sub(context);
+        let mut end = i;
+        loop {
+            while end < ops.len() && ops[end].kind != Kind::Equal {
+                end += 1;
+            }
+            let mut next_change = end;
+            while next_change < ops.len() && ops[next_change].kind == Kind::Equal {
+                next_change += 1;
+            }
+            if next_change == ops.len() || next_change - end > 2 * context {
+                break;
+            }
+            end = next_change;
+        }
+        let end = (end + context).min(ops.len());
+
+        let hunk = &ops[start..end];
+        let old_count = hunk.iter().filter(|op| op.kind != Kind::Insert).count();
+        let new_count = hunk.iter().filter(|op| op.kind != Kind::Delete).count();
+        writeln!(
+            out,
+            "@@ -{} +{} @@",
+            unified_range(hunk[0].a, old_count),
+            unified_range(hunk[0].b, new_count)
+        )?;
+        for op in hunk {
+            match op.kind {
+                Kind::Equal => write_line(out, " ", old[op.a])?,
+                Kind::Delete => write_line(out, "-", old[op.a])?,
+                Kind::Insert => write_line(out, "+", new[op.b])?,
+            }
+        }
+        i = end;
+    }
+    Ok(())
+}
+
+// `first[,last]` in 1-based line numbers for the normal output format.
+fn normal_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{}", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, start + count),
+    }
+}
+
+fn write_normal(out: &mut dyn Write, ops: &[Op], old: &[&[u8]], new: &[&[u8]]) -> io::Result<()> {
+    let mut i = 0;
+    while i < ops.len() {
+        if ops[i].kind == Kind::Equal {
+            i += 1;
+            continue;
+        }
+        let start = ops[i];
+        let mut end = i;
+        while end < ops.len() && ops[end].kind != Kind::Equal {
+            end += 1;
+        }
+        let block = &ops[i..end];
+        let deleted: Vec<usize> = block
+            .iter()
+            .filter(|op| op.kind == Kind::Delete)
+            .map(|op| op.a)
+            .collect();
+        let inserted: Vec<usize> = block
+            .iter()
+            .filter(|op| op.kind == Kind::Insert)
+            .map(|op| op.b)
+            .collect();
+
+        let action = match (deleted.is_empty(), inserted.is_empty()) {
+            (false, true) => 'd',
+            (true, false) => 'a',
+            _ => 'c',
+        };
+        writeln!(
+            out,
+            "{}{}{}",
+            normal_range(start.a, deleted.len()),
+            action,
+            normal_range(start.b, inserted.len())
+        )?;
+        for &line in &deleted {
+            write_line(out, "< ", old[line])?;
+        }
+        if action == 'c' {
+            writeln!(out, "---")?;
+        }
+        for &line in &inserted {
+            write_line(out, "> ", new[line])?;
+        }
+        i = end;
+    }
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ls::format_size, walk::Walk};
+use std::{mem, path::Path};
+
+// Sizes are the apparent file lengths, block usage is not meaningful on every file system.
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut human = false;
+    let mut max_depth = usize::MAX;
+    let mut paths = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-h" | "--human-readable" => human = true,
+            "-s" | "--summarize" => max_depth = 0,
+            "-sh" | "-hs" => {
+                human = true;
+                max_depth = 0;
+            }
+            "-d" | "--max-depth" => {
+                let depth = iter.next().ok_or("option requires an argument -- 'd'")?;
+                max_depth = depth
+                    .parse()
+                    .map_err(|_| format!("invalid maximum depth '{}'", depth))?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => paths.push(*arg),
+        }
+    }
+    if paths.is_empty() {
+        paths.push(".");
+    }
+
+    for path in paths {
+        // totals[n] accumulates the entries seen at depth n until their parent is visited.
+        let mut totals: Vec<u64> = Vec::new();
+        Walk::new()
+            .contents_first()
+            .run(Path::new(path), &mut |entry| {
+                if totals.len() < entry.depth + 2 {
+                    totals.resize(entry.depth + 2, 0);
+                }
+                let mut size = 0;
+                if entry.metadata.is_dir() {
+                    size = mem::take(&mut totals[entry.depth + 1]);
+                } else if !entry.metadata.file_type().is_symlink() {
+                    size = entry.metadata.len();
+                }
+                totals[entry.depth] += size;
+
+                if entry.depth == 0 || (entry.metadata.is_dir() && entry.depth <= max_depth) {
+                    let size = if human {
+                        format_size(size)
+                    } else {
+                        size.div_ceil(1024).to_string()
+                    };
+                    println!("{}\t{}", size, entry.path.display());
+                }
+                Ok(())
+            })?;
+    }
+    Ok(())
+}
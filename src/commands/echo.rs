@@ -12,23 +12,76 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{fs::File, io::Write};
+use super::printf::expand_escapes;
+use std::{
+    fs::OpenOptions,
+    io::{self, Write},
+};
 
 pub fn command(args: &[&str]) -> Result<(), String> {
-    if let Some(pos) = args.iter().position(|&x| x == ">") {
-        if pos + 1 >= args.len() {
-            return Err("Missing filename after '>'".to_string());
+    let mut newline = true;
+    let mut escapes = false;
+
+    // Leading arguments made only of option letters are options, anything else is text.
+    let mut args = args;
+    while let Some(arg) = args.first() {
+        let Some(flags) = arg.strip_prefix('-') else {
+            break;
+        };
+        if flags.is_empty() || !flags.chars().all(|c| matches!(c, 'n' | 'e' | 'E')) {
+            break;
+        }
+        for flag in flags.chars() {
+            match flag {
+                'n' => newline = false,
+                'e' => escapes = true,
+                _ => escapes = false,
+            }
         }
-        let content = &args[..pos];
-        let filename = args[pos + 1];
-        let mut file =
-            File::create(filename).map_err(|e| format!("Failed to create file: {}", e))?;
+        args = &args[1..];
+    }
 
-        writeln!(file, "{}", content.join(" "))
-            .map_err(|e| format!("Failed to write to file: {}", e))?;
-    } else {
-        println!("{}", args.join(" "));
+    // Redirection is handled here so control files can be written without a
+    // generic layer; `>>` appends instead of truncating.
+    let mut target = None;
+    if let Some(pos) = args.iter().position(|&x| x == ">" || x == ">>") {
+        let filename = args
+            .get(pos + 1)
+            .ok_or(format!("Missing filename after '{}'", args[pos]))?;
+        target = Some((*filename, args[pos] == ">>"));
+        args = &args[..pos];
     }
 
-    Ok(())
+    let mut output = args.join(" ").into_bytes();
+    if escapes {
+        let (expanded, keep_going) = expand_escapes(&output, true)?;
+        output = expanded;
+        // `\c` also suppresses the trailing newline.
+        newline &= keep_going;
+    }
+    if newline {
+        output.push(b'\n');
+    }
+
+    match target {
+        Some((filename, append)) => {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .append(append)
+                .truncate(!append)
+                .open(filename)
+                .map_err(|e| format!("Failed to open file '{}': {}", filename, e))?;
+            // A single write, so control files see the whole value at once.
+            file.write_all(&output)
+                .map_err(|e| format!("Failed to write to file: {}", e))
+        }
+        None => {
+            let mut stdout = io::stdout().lock();
+            stdout
+                .write_all(&output)
+                .and_then(|_| stdout.flush())
+                .map_err(|e| e.to_string())
+        }
+    }
 }
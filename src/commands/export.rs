@@ -0,0 +1,73 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+
+// Variables live in the shell process, every command sees them, e.g. LS_COLORS and COLUMNS.
+pub fn command(args: &[&str]) -> Result<(), String> {
+    if args.is_empty() {
+        let mut vars: Vec<_> = env::vars().collect();
+        vars.sort();
+        for (name, value) in vars {
+            println!("{}={}", name, value);
+        }
+        return Ok(());
+    }
+
+    for arg in args {
+        let (name, value) = arg.split_once('=').ok_or(format!(
+            "invalid argument '{}', expected <name>=<value>",
+            arg
+        ))?;
+        check_name(name)?;
+        env::set_var(name, unquote(value));
+    }
+    Ok(())
+}
+
+pub fn unset(args: &[&str]) -> Result<(), String> {
+    if args.is_empty() {
+        return Err("Usage: unset <name>...".to_string());
+    }
+    for name in args {
+        check_name(name)?;
+        env::remove_var(name);
+    }
+    Ok(())
+}
+
+fn check_name(name: &str) -> Result<(), String> {
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!("'{}': not a valid identifier", name));
+    }
+    Ok(())
+}
+
+// The command line is split on whitespace only, so drop one pair of surrounding quotes.
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
@@ -0,0 +1,252 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    regex::Regex,
+    walk::{Entry, Walk},
+    CommandInfo, COMMANDS,
+};
+use std::{
+    cmp::Ordering,
+    os::unix::fs::MetadataExt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+enum Test {
+    Name(Regex),
+    Type(char),
+    // Size in units of `unit` bytes, rounded up like find does.
+    Size(Ordering, u64, u64),
+    // Age in whole days.
+    Mtime(Ordering, i64),
+}
+
+enum Action {
+    Print,
+    Exec(&'static CommandInfo, Vec<String>),
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut paths = Vec::new();
+    let mut tests = Vec::new();
+    let mut actions = Vec::new();
+    let mut max_depth = usize::MAX;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .copied()
+                .ok_or(format!("missing argument to '{}'", arg))
+        };
+        match *arg {
+            "-name" | "-iname" => {
+                let regex = Regex::new(&glob_to_regex(unquote(value()?)), true, *arg == "-iname")?;
+                tests.push(Test::Name(regex));
+            }
+            "-type" => match value()? {
+                kind @ ("f" | "d" | "l") => tests.push(Test::Type(kind.chars().next().unwrap())),
+                kind => return Err(format!("unknown argument to -type: {}", kind)),
+            },
+            "-size" => {
+                let (ordering, size) = split_comparison(value()?);
+                let (size, unit) = match size.char_indices().last() {
+                    Some((i, 'c')) => (&size[..i], 1),
+                    Some((i, 'w')) => (&size[..i], 2),
+                    Some((i, 'b')) => (&size[..i], 512),
+                    Some((i, 'k')) => (&size[..i], 1024),
+                    Some((i, 'M')) => (&size[..i], 1024 * 1024),
+                    Some((i, 'G')) => (&size[..i], 1024 * 1024 * 1024),
+                    _ => (size, 512),
+                };
+                let size = size
+                    .parse()
+                    .map_err(|_| format!("invalid argument to -size: {}", size))?;
+                tests.push(Test::Size(ordering, size, unit));
+            }
+            "-mtime" => {
+                let (ordering, days) = split_comparison(value()?);
+                let days = days
+                    .parse()
+                    .map_err(|_| format!("invalid argument to -mtime: {}", days))?;
+                tests.push(Test::Mtime(ordering, days));
+            }
+            "-maxdepth" => {
+                let depth = value()?;
+                max_depth = depth
+                    .parse()
+                    .map_err(|_| format!("invalid argument to -maxdepth: {}", depth))?;
+            }
+            "-print" => actions.push(Action::Print),
+            "-exec" => {
+                let name = value()?;
+                let info = COMMANDS
+                    .get(name)
+                    .ok_or(format!("-exec: unknown command '{}'", name))?;
+                let mut exec_args = Vec::new();
+                loop {
+                    match value()? {
+                        ";" | "\\;" => break,
+                        exec_arg => exec_args.push(exec_arg.to_string()),
+                    }
+                }
+                actions.push(Action::Exec(info, exec_args));
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown predicate '{}'", arg)),
+            _ if tests.is_empty() && actions.is_empty() => paths.push(*arg),
+            _ => return Err(format!("paths must precede expression: '{}'", arg)),
+        }
+    }
+
+    if paths.is_empty() {
+        paths.push(".");
+    }
+    if actions.is_empty() {
+        actions.push(Action::Print);
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs() as i64;
+
+    let walk = Walk::new().max_depth(max_depth);
+    for path in paths {
+        walk.run(path.as_ref(), &mut |entry| {
+            if tests.iter().all(|test| matches(test, entry, now)) {
+                // Like `find`, later actions only run while earlier ones succeed.
+                for action in &actions {
+                    if !perform(action, entry) {
+                        break;
+                    }
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+fn matches(test: &Test, entry: &Entry, now: i64) -> bool {
+    let metadata = &entry.metadata;
+    match test {
+        Test::Name(regex) => {
+            // The starting path is matched on its last component too, `/` included.
+            let name = entry
+                .path
+                .file_name()
+                .map(|name| name.to_string_lossy())
+                .unwrap_or_else(|| entry.path.to_string_lossy());
+            regex.is_match(&name)
+        }
+        Test::Type(kind) => match kind {
+            'f' => metadata.is_file(),
+            'd' => metadata.is_dir(),
+            _ => metadata.file_type().is_symlink(),
+        },
+        Test::Size(ordering, size, unit) => metadata.len().div_ceil(*unit).cmp(size) == *ordering,
+        Test::Mtime(ordering, days) => {
+            ((now - metadata.mtime()).div_euclid(SECONDS_PER_DAY)).cmp(days) == *ordering
+        }
+    }
+}
+
+fn perform(action: &Action, entry: &Entry) -> bool {
+    let path = entry.path.to_string_lossy();
+    match action {
+        Action::Print => {
+            println!("{}", path);
+            true
+        }
+        Action::Exec(info, args) => {
+            let args: Vec<String> = args.iter().map(|arg| arg.replace("{}", &path)).collect();
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            match (info.handler)(&args) {
+                Ok(()) => true,
+                Err(e) => {
+                    if !e.is_empty() {
+                        println!("Error: {}", e);
+                    }
+                    false
+                }
+            }
+        }
+    }
+}
+
+// `+N` means more than N, `-N` less than N and a bare `N` exactly N.
+fn split_comparison(value: &str) -> (Ordering, &str) {
+    if let Some(rest) = value.strip_prefix('+') {
+        (Ordering::Greater, rest)
+    } else if let Some(rest) = value.strip_prefix('-') {
+        (Ordering::Less, rest)
+    } else {
+        (Ordering::Equal, value)
+    }
+}
+
+// The shell does not strip quotes, so accept `-name '*.log'` as typed.
+fn unquote(value: &str) -> &str {
+    for quote in ['\'', '"'] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+// Turns a shell pattern into an anchored extended regex.
+fn glob_to_regex(pattern: &str) -> String {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            '[' => {
+                regex.push('[');
+                if chars.next_if(|&c| c == '!' || c == '^').is_some() {
+                    regex.push('^');
+                }
+                // A `]` right after the opening bracket is part of the set.
+                if let Some(c) = chars.next_if_eq(&']') {
+                    regex.push(c);
+                }
+                for c in chars.by_ref() {
+                    regex.push(c);
+                    if c == ']' {
+                        break;
+                    }
+                }
+            }
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    regex.push('\\');
+                    regex.push(next);
+                }
+            }
+            '.' | '+' | '(' | ')' | '{' | '}' | '|' | '^' | '$' => {
+                regex.push('\\');
+                regex.push(c);
+            }
+            _ => regex.push(c),
+        }
+    }
+    regex.push('$');
+    regex
+}
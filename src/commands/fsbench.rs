@@ -0,0 +1,223 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{reader, truncate::parse_size};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    mem,
+    path::Path,
+    process,
+};
+
+const DEFAULT_SIZE: u64 = 4 * 1024 * 1024;
+const DEFAULT_COUNT: usize = 256;
+const CHUNK_SIZE: usize = 64 * 1024;
+const RANDOM_BLOCK: usize = 4096;
+const LISTING_ROUNDS: usize = 100;
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut size = DEFAULT_SIZE;
+    let mut count = DEFAULT_COUNT;
+    let mut dir = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-s" => size = parse_size(iter.next().ok_or("option requires an argument -- 's'")?)?,
+            "-n" => {
+                let value = iter.next().ok_or("option requires an argument -- 'n'")?;
+                count = value
+                    .parse()
+                    .ok()
+                    .filter(|count| *count > 0)
+                    .ok_or(format!("invalid count '{}'", value))?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ if dir.is_none() => dir = Some(*arg),
+            _ => return Err(format!("extra operand '{}'", arg)),
+        }
+    }
+    let dir = dir.ok_or("Usage: fsbench [-s <file size>] [-n <count>] <directory>")?;
+    if size < RANDOM_BLOCK as u64 {
+        return Err(format!("file size must be at least {} bytes", RANDOM_BLOCK));
+    }
+
+    let work_dir = Path::new(dir).join(format!("fsbench.{}", process::id()));
+    fs::create_dir(&work_dir)
+        .map_err(|e| format!("Unable to create '{}': {}", work_dir.display(), e))?;
+    let result = run(&work_dir, size, count);
+    // Clean up even when a phase failed, the board may have little space.
+    let cleanup = fs::remove_dir_all(&work_dir)
+        .map_err(|e| format!("Unable to remove '{}': {}", work_dir.display(), e));
+    result.and(cleanup)
+}
+
+fn run(dir: &Path, size: u64, count: usize) -> Result<(), String> {
+    let data_path = dir.join("data");
+    let io_err = |what: &str, e: std::io::Error| format!("{} failed: {}", what, e);
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut random = Random::new();
+    random.fill(&mut buffer);
+
+    println!("{:<16} {:>12} {:>14}", "test", "amount", "rate");
+
+    // Sequential write, synced so the numbers include reaching the backend.
+    let timer = Timer::start();
+    let mut file = File::create(&data_path).map_err(|e| io_err("create", e))?;
+    let mut written = 0;
+    while written < size {
+        let len = (size - written).min(CHUNK_SIZE as u64) as usize;
+        file.write_all(&buffer[..len])
+            .map_err(|e| io_err("write", e))?;
+        written += len as u64;
+    }
+    file.sync_all().map_err(|e| io_err("sync", e))?;
+    drop(file);
+    report_throughput("seq write", size, timer.elapsed());
+
+    let timer = Timer::start();
+    let mut file = File::open(&data_path).map_err(|e| io_err("open", e))?;
+    let mut read = 0;
+    loop {
+        let n = reader::read_full(&mut file, &mut buffer).map_err(|e| io_err("read", e))?;
+        if n == 0 {
+            break;
+        }
+        read += n as u64;
+    }
+    report_throughput("seq read", read, timer.elapsed());
+
+    // Random 4K I/O at block aligned offsets inside the data file.
+    let blocks = size / RANDOM_BLOCK as u64;
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&data_path)
+        .map_err(|e| io_err("open", e))?;
+    let block = &mut buffer[..RANDOM_BLOCK];
+
+    let timer = Timer::start();
+    for _ in 0..count {
+        let offset = random.below(blocks) * RANDOM_BLOCK as u64;
+        file.seek(SeekFrom::Start(offset))
+            .map_err(|e| io_err("seek", e))?;
+        file.read_exact(block).map_err(|e| io_err("read", e))?;
+    }
+    report_ops("rand read 4K", count, timer.elapsed());
+
+    let timer = Timer::start();
+    for _ in 0..count {
+        let offset = random.below(blocks) * RANDOM_BLOCK as u64;
+        file.seek(SeekFrom::Start(offset))
+            .map_err(|e| io_err("seek", e))?;
+        file.write_all(block).map_err(|e| io_err("write", e))?;
+    }
+    file.sync_all().map_err(|e| io_err("sync", e))?;
+    report_ops("rand write 4K", count, timer.elapsed());
+    drop(file);
+    fs::remove_file(&data_path).map_err(|e| io_err("remove", e))?;
+
+    let names: Vec<_> = (0..count).map(|i| dir.join(format!("f{:06}", i))).collect();
+    let timer = Timer::start();
+    for name in &names {
+        File::create(name).map_err(|e| io_err("create", e))?;
+    }
+    report_ops("create", count, timer.elapsed());
+
+    // The files just created are listed before they are deleted.
+    let timer = Timer::start();
+    for _ in 0..LISTING_ROUNDS {
+        for entry in fs::read_dir(dir).map_err(|e| io_err("read_dir", e))? {
+            entry.map_err(|e| io_err("read_dir", e))?;
+        }
+    }
+    report_ops("list dir", LISTING_ROUNDS, timer.elapsed());
+
+    let timer = Timer::start();
+    for name in &names {
+        fs::remove_file(name).map_err(|e| io_err("remove", e))?;
+    }
+    report_ops("delete", count, timer.elapsed());
+
+    Ok(())
+}
+
+fn report_throughput(test: &str, bytes: u64, seconds: f64) {
+    let megabytes = bytes as f64 / (1024.0 * 1024.0);
+    println!(
+        "{:<16} {:>9.1} MB {:>9.1} MB/s",
+        test,
+        megabytes,
+        megabytes / seconds
+    );
+}
+
+fn report_ops(test: &str, ops: usize, seconds: f64) {
+    println!(
+        "{:<16} {:>8} ops {:>8.0} ops/s",
+        test,
+        ops,
+        ops as f64 / seconds
+    );
+}
+
+/// Measures with CLOCK_MONOTONIC so wall clock adjustments do not skew results.
+struct Timer {
+    start: f64,
+}
+
+impl Timer {
+    fn start() -> Self {
+        Timer { start: now() }
+    }
+
+    // Never zero, rates stay finite on very fast backends.
+    fn elapsed(&self) -> f64 {
+        (now() - self.start).max(1e-9)
+    }
+}
+
+fn now() -> f64 {
+    let mut ts: libc::timespec = unsafe { mem::zeroed() };
+    unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts) };
+    ts.tv_sec as f64 + ts.tv_nsec as f64 / 1e9
+}
+
+// xorshift64, good enough to spread offsets and fill buffers.
+struct Random(u64);
+
+impl Random {
+    fn new() -> Self {
+        Random((now() * 1e9) as u64 | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn fill(&mut self, buffer: &mut [u8]) {
+        for chunk in buffer.chunks_mut(8) {
+            let bytes = self.next().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
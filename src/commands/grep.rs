@@ -0,0 +1,152 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{reader, regex::Regex, walk::Walk};
+use std::{
+    io::{self, Write},
+    path::Path,
+};
+
+#[derive(Default)]
+struct Options {
+    ignore_case: bool,
+    invert: bool,
+    line_numbers: bool,
+    count: bool,
+    recursive: bool,
+    files_with_matches: bool,
+    extended: bool,
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut options = Options::default();
+    let mut pattern = None;
+    let mut files = Vec::new();
+    let mut end_of_options = false;
+
+    for arg in args {
+        if *arg == "--" && !end_of_options {
+            end_of_options = true;
+        } else if arg.starts_with('-') && arg.len() > 1 && pattern.is_none() && !end_of_options {
+            // Flags may be combined, e.g. `grep -in`.
+            for flag in arg[1..].chars() {
+                match flag {
+                    'i' => options.ignore_case = true,
+                    'v' => options.invert = true,
+                    'n' => options.line_numbers = true,
+                    'c' => options.count = true,
+                    'r' | 'R' => options.recursive = true,
+                    'l' => options.files_with_matches = true,
+                    'E' => options.extended = true,
+                    _ => return Err(format!("invalid option -- '{}'", flag)),
+                }
+            }
+        } else if pattern.is_none() {
+            pattern = Some(*arg);
+        } else {
+            files.push(*arg);
+        }
+    }
+
+    let pattern =
+        pattern.ok_or("Usage: grep [-i] [-v] [-n] [-c] [-r] [-l] [-E] <pattern> [<file>...]")?;
+    let regex = Regex::new(pattern, options.extended, options.ignore_case)
+        .map_err(|e| format!("invalid pattern '{}': {}", pattern, e))?;
+
+    let files = if files.is_empty() && options.recursive {
+        vec!["."]
+    } else {
+        reader::inputs(&files)
+    };
+    let show_names = files.len() > 1 || options.recursive;
+
+    let mut out = io::stdout().lock();
+    for file in files {
+        let path = Path::new(file);
+        if options.recursive && path.is_dir() {
+            grep_dir(path, &regex, &options, &mut out)?;
+        } else if let Err(e) = grep_file(file, &regex, &options, show_names, &mut out) {
+            // Keep going like grep does, one unreadable file should not hide other matches.
+            eprintln!("grep: {}", e);
+        }
+    }
+    out.flush().map_err(|e| e.to_string())
+}
+
+fn grep_dir(
+    dir: &Path,
+    regex: &Regex,
+    options: &Options,
+    out: &mut dyn Write,
+) -> Result<(), String> {
+    Walk::new().run(dir, &mut |entry| {
+        // Links met while descending are skipped, as grep -r does.
+        if entry.metadata.is_file() {
+            if let Err(e) = grep_file(&entry.path.to_string_lossy(), regex, options, true, out) {
+                eprintln!("grep: {}", e);
+            }
+        }
+        Ok(())
+    })
+}
+
+fn grep_file(
+    name: &str,
+    regex: &Regex,
+    options: &Options,
+    show_name: bool,
+    out: &mut dyn Write,
+) -> Result<(), String> {
+    let display_name = if name == reader::STDIN {
+        "(standard input)"
+    } else {
+        name
+    };
+    let mut input = reader::open(name)?;
+    let mut line_no = 0;
+    let mut matches = 0;
+
+    while let Some(line) = reader::read_line(&mut input, name)? {
+        line_no += 1;
+        let text = String::from_utf8_lossy(&line);
+        let text = text.trim_end_matches(['\n', '\r']);
+        if regex.is_match(text) == options.invert {
+            continue;
+        }
+
+        matches += 1;
+        if options.files_with_matches {
+            writeln!(out, "{}", display_name).map_err(|e| e.to_string())?;
+            return Ok(());
+        }
+        if options.count {
+            continue;
+        }
+        if show_name {
+            write!(out, "{}:", display_name).map_err(|e| e.to_string())?;
+        }
+        if options.line_numbers {
+            write!(out, "{}:", line_no).map_err(|e| e.to_string())?;
+        }
+        writeln!(out, "{}", text).map_err(|e| e.to_string())?;
+    }
+
+    if options.count && !options.files_with_matches {
+        if show_name {
+            write!(out, "{}:", display_name).map_err(|e| e.to_string())?;
+        }
+        writeln!(out, "{}", matches).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
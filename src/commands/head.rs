@@ -0,0 +1,74 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+use std::io::{self, Read, Write};
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut lines = 10;
+    let mut bytes = None;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-n" | "--lines" => {
+                lines = parse_count(iter.next().ok_or("option requires an argument -- 'n'")?)?
+            }
+            "-c" | "--bytes" => {
+                bytes = Some(parse_count(
+                    iter.next().ok_or("option requires an argument -- 'c'")?,
+                )?)
+            }
+            reader::STDIN => files.push(*arg),
+            // `head -5` is shorthand for `head -n 5`.
+            _ if arg.starts_with('-') => lines = parse_count(&arg[1..])?,
+            _ => files.push(*arg),
+        }
+    }
+
+    let files = reader::inputs(&files);
+    let mut out = io::stdout().lock();
+
+    for (i, name) in files.iter().enumerate() {
+        if files.len() > 1 {
+            let separator = if i == 0 { "" } else { "\n" };
+            writeln!(out, "{}==> {} <==", separator, name).map_err(|e| e.to_string())?;
+        }
+
+        let mut input = reader::open(name)?;
+        match bytes {
+            Some(count) => {
+                io::copy(&mut input.take(count), &mut out)
+                    .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+            }
+            None => {
+                for _ in 0..lines {
+                    match reader::read_line(&mut input, name)? {
+                        Some(line) => out.write_all(&line).map_err(|e| e.to_string())?,
+                        None => break,
+                    }
+                }
+            }
+        }
+    }
+
+    out.flush().map_err(|e| e.to_string())
+}
+
+fn parse_count(value: &str) -> Result<u64, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid number '{}'", value))
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader::{self, open_at, parse_offset};
+use std::io::{self, Read, Write};
+
+const BYTES_PER_LINE: usize = 16;
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut skip = 0;
+    let mut length = None;
+    let mut no_squeeze = false;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            // Canonical hex+ASCII is the only format, -C is accepted for familiarity.
+            "-C" | "--canonical" => {}
+            "-s" | "--skip" => {
+                skip = parse_offset(iter.next().ok_or("option requires an argument -- 's'")?)?
+            }
+            "-n" | "--length" => {
+                length = Some(parse_offset(
+                    iter.next().ok_or("option requires an argument -- 'n'")?,
+                )?)
+            }
+            "-v" | "--no-squeezing" => no_squeeze = true,
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+    if files.len() > 1 {
+        return Err("Usage: hexdump [-C] [-v] [-s <offset>] [-n <length>] [<file>]".to_string());
+    }
+
+    let name = reader::inputs(&files)[0];
+    let mut input = open_at(name, skip)?;
+    let mut input: Box<dyn Read> = match length {
+        Some(length) => Box::new(input.take(length)),
+        None => Box::new(&mut input),
+    };
+
+    let mut out = io::stdout().lock();
+    let mut offset = skip;
+    let mut previous: Option<[u8; BYTES_PER_LINE]> = None;
+    let mut squeezing = false;
+    let mut line = [0u8; BYTES_PER_LINE];
+
+    loop {
+        let read = reader::read_full(&mut input, &mut line)
+            .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+        if read == 0 {
+            break;
+        }
+
+        // Runs of identical full lines collapse into a single `*`.
+        if !no_squeeze && read == BYTES_PER_LINE && previous == Some(line) {
+            if !squeezing {
+                writeln!(out, "*").map_err(|e| e.to_string())?;
+                squeezing = true;
+            }
+        } else {
+            squeezing = false;
+            write_canonical_line(&mut out, offset, &line[..read]).map_err(|e| e.to_string())?;
+        }
+
+        previous = (read == BYTES_PER_LINE).then_some(line);
+        offset += read as u64;
+    }
+
+    writeln!(out, "{:08x}", offset).map_err(|e| e.to_string())?;
+    out.flush().map_err(|e| e.to_string())
+}
+
+fn write_canonical_line(out: &mut dyn Write, offset: u64, bytes: &[u8]) -> io::Result<()> {
+    write!(out, "{:08x} ", offset)?;
+    for i in 0..BYTES_PER_LINE {
+        if i % 8 == 0 {
+            write!(out, " ")?;
+        }
+        match bytes.get(i) {
+            Some(byte) => write!(out, "{:02x} ", byte)?,
+            None => write!(out, "   ")?,
+        }
+    }
+    writeln!(out, " |{}|", printable(bytes))
+}
+
+/// Renders bytes as ASCII, replacing anything non-printable with `.`.
+pub fn printable(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,92 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    fs,
+    os::unix::fs::symlink,
+    path::{Path, PathBuf},
+};
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut symbolic = false;
+    let mut force = false;
+    let mut verbose = false;
+    let mut operands = Vec::new();
+
+    for arg in args {
+        match *arg {
+            "-s" | "--symbolic" => symbolic = true,
+            "-f" | "--force" => force = true,
+            "-v" | "--verbose" => verbose = true,
+            "-sf" | "-fs" => {
+                symbolic = true;
+                force = true;
+            }
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => operands.push(*arg),
+        }
+    }
+
+    if operands.is_empty() {
+        return Err("Usage: ln [-s] [-f] [-v] <target>... [<link name>|<directory>]".to_string());
+    }
+
+    let links: Vec<(&str, PathBuf)> = match operands.as_slice() {
+        [target] => vec![(*target, link_name_in(Path::new("."), target)?)],
+        [target, link] if !Path::new(link).is_dir() => vec![(*target, PathBuf::from(link))],
+        [targets @ .., dir] => {
+            let dir = Path::new(dir);
+            if !dir.is_dir() {
+                return Err(format!("target '{}' is not a directory", dir.display()));
+            }
+            targets
+                .iter()
+                .map(|target| Ok((*target, link_name_in(dir, target)?)))
+                .collect::<Result<_, String>>()?
+        }
+        [] => unreachable!(),
+    };
+
+    for (target, link) in links {
+        if force && fs::symlink_metadata(&link).is_ok() {
+            fs::remove_file(&link)
+                .map_err(|e| format!("cannot remove '{}': {}", link.display(), e))?;
+        }
+
+        let result = if symbolic {
+            symlink(target, &link)
+        } else {
+            fs::hard_link(target, &link)
+        };
+        result.map_err(|e| format!("failed to create link '{}': {}", link.display(), e))?;
+
+        if verbose {
+            println!(
+                "'{}' {} '{}'",
+                link.display(),
+                if symbolic { "->" } else { "=>" },
+                target
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn link_name_in(dir: &Path, target: &str) -> Result<PathBuf, String> {
+    Path::new(target)
+        .file_name()
+        .map(|name| dir.join(name))
+        .ok_or_else(|| format!("Invalid target name '{}'", target))
+}
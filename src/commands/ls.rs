@@ -12,85 +12,384 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{fs, os::unix::fs::MetadataExt, path::Path};
+use super::stat::format_timestamp;
+use std::{
+    cmp::Reverse,
+    env, fs,
+    io::{self, IsTerminal},
+    mem,
+    os::unix::fs::MetadataExt,
+    path::{Path, PathBuf},
+};
+
+const DEFAULT_WIDTH: usize = 80;
+const COLUMN_GAP: usize = 2;
+
+#[derive(Default, PartialEq)]
+enum Sort {
+    #[default]
+    Name,
+    Time,
+    Size,
+}
+
+#[derive(Default)]
+struct Options {
+    show_hidden: bool,
+    long_format: bool,
+    recursive: bool,
+    sort: Sort,
+    reverse: bool,
+    human: bool,
+    inode: bool,
+    directory: bool,
+    one_per_line: bool,
+    colors: Option<Colors>,
+    // Terminal width when names are laid out in columns.
+    width: Option<usize>,
+}
+
+#[derive(PartialEq)]
+enum When {
+    Always,
+    Never,
+    Auto,
+}
+
+/// SGR sequences per file kind, taken from `LS_COLORS` (`export LS_COLORS=di=01;34:ln=01;36:...`).
+struct Colors {
+    dir: String,
+    link: String,
+    exec: String,
+    file: String,
+}
+
+impl Colors {
+    fn from_env() -> Self {
+        let mut colors = Colors {
+            dir: "01;34".to_string(),
+            link: "01;36".to_string(),
+            exec: "01;32".to_string(),
+            file: String::new(),
+        };
+        let spec = env::var("LS_COLORS").unwrap_or_default();
+        for (key, value) in spec.split(':').filter_map(|entry| entry.split_once('=')) {
+            let value = value.to_string();
+            match key {
+                "di" => colors.dir = value,
+                "ln" => colors.link = value,
+                "ex" => colors.exec = value,
+                "fi" => colors.file = value,
+                _ => {}
+            }
+        }
+        colors
+    }
+
+    fn paint(&self, name: &str, metadata: &fs::Metadata) -> String {
+        let code = if metadata.file_type().is_symlink() {
+            &self.link
+        } else if metadata.is_dir() {
+            &self.dir
+        } else if metadata.mode() & 0o111 != 0 {
+            &self.exec
+        } else {
+            &self.file
+        };
+        if code.is_empty() || code == "0" {
+            name.to_string()
+        } else {
+            format!("\x1b[{}m{}\x1b[0m", code, name)
+        }
+    }
+}
+
+struct Item {
+    name: String,
+    path: PathBuf,
+    metadata: fs::Metadata,
+}
 
 pub fn command(args: &[&str]) -> Result<(), String> {
-    let mut show_hidden = false;
-    let mut long_format = false;
-    let mut target_path = ".";
+    let mut options = Options::default();
+    let mut operands = Vec::new();
+    let mut color = When::Auto;
 
     for arg in args {
-        match arg {
-            &"-a" => show_hidden = true,
-            &"-l" => long_format = true,
-            &"-la" | &"-al" => {
-                show_hidden = true;
-                long_format = true;
+        if let Some(when) = arg.strip_prefix("--color") {
+            color = match when {
+                "" | "=always" => When::Always,
+                "=never" => When::Never,
+                "=auto" => When::Auto,
+                _ => return Err(format!("invalid argument '{}' for '--color'", when)),
+            };
+            continue;
+        }
+        if !arg.starts_with('-') || arg.len() == 1 {
+            operands.push(*arg);
+            continue;
+        }
+        for flag in arg[1..].chars() {
+            match flag {
+                'a' => options.show_hidden = true,
+                'l' => options.long_format = true,
+                'R' => options.recursive = true,
+                't' => options.sort = Sort::Time,
+                'S' => options.sort = Sort::Size,
+                'r' => options.reverse = true,
+                'h' => options.human = true,
+                'i' => options.inode = true,
+                'd' => options.directory = true,
+                '1' => options.one_per_line = true,
+                _ => return Err(format!("Unknown option: -{}", flag)),
             }
-            path if !path.starts_with('-') => target_path = path,
-            _ => return Err(format!("Unknown option: {}", arg)),
         }
     }
+    if operands.is_empty() {
+        operands.push(".");
+    }
 
-    let path = Path::new(target_path);
+    // Pipes and files get the plain one-name-per-line format scripts expect.
+    let terminal = io::stdout().is_terminal();
+    if color == When::Always || (color == When::Auto && terminal) {
+        options.colors = Some(Colors::from_env());
+    }
+    if terminal && !options.one_per_line && !options.long_format {
+        options.width = Some(terminal_width());
+    }
 
-    if !path.exists() {
-        return Err(format!("Directory does not exist: {}", target_path));
+    let mut failed = false;
+    let mut files = Vec::new();
+    let mut dirs = Vec::new();
+    for operand in &operands {
+        let path = Path::new(operand);
+        let metadata = match fs::symlink_metadata(path) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                println!("ls: cannot access '{}': {}", operand, e);
+                failed = true;
+                continue;
+            }
+        };
+        // A link to a directory named on the command line is listed through,
+        // unless its own details were asked for.
+        let list_contents =
+            !options.directory && (metadata.is_dir() || (!options.long_format && path.is_dir()));
+        let item = Item {
+            name: operand.to_string(),
+            path: path.to_path_buf(),
+            metadata,
+        };
+        if list_contents {
+            dirs.push(item);
+        } else {
+            files.push(item);
+        }
     }
 
-    let entries = fs::read_dir(path).map_err(|e| format!("Unable to read directory: {}", e))?;
-    let mut items: Vec<_> = entries
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| entry.path().file_name().is_some())
-        .collect();
-    items.sort_by(|a, b| {
-        a.path()
-            .file_name()
-            .unwrap_or_default()
-            .cmp(b.path().file_name().unwrap_or_default())
-    });
+    sort_items(&mut files, &options);
+    sort_items(&mut dirs, &options);
+    print_items(&files, &options);
 
-    for entry in items {
-        let path = entry.path();
-        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let show_headers = operands.len() > 1 || options.recursive;
+    let mut first = files.is_empty();
+    for dir in dirs {
+        list_tree(&dir.path, &options, show_headers, &mut first, &mut failed);
+    }
+
+    if failed {
+        // Every problem was already reported next to the listing.
+        return Err(String::new());
+    }
+    Ok(())
+}
+
+// Lists `path` and with -R its subdirectories, in the same order as they were listed.
+fn list_tree(
+    path: &Path,
+    options: &Options,
+    show_headers: bool,
+    first: &mut bool,
+    failed: &mut bool,
+) {
+    if !*first {
+        println!();
+    }
+    *first = false;
+    if show_headers {
+        println!("{}:", path.display());
+    }
+    let items = match list_dir(path, options) {
+        Ok(items) => items,
+        Err(e) => {
+            println!("ls: {}", e);
+            *failed = true;
+            return;
+        }
+    };
+    if options.recursive {
+        // Hidden entries are already left out without -a, and links are not
+        // followed, so a link back up the tree cannot loop.
+        for item in items.iter().filter(|item| item.metadata.is_dir()) {
+            list_tree(&item.path, options, show_headers, first, failed);
+        }
+    }
+}
 
-        if !show_hidden && file_name.starts_with('.') {
+fn list_dir(path: &Path, options: &Options) -> Result<Vec<Item>, String> {
+    let entries = fs::read_dir(path)
+        .map_err(|e| format!("Unable to read directory '{}': {}", path.display(), e))?;
+    let mut items = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read dir entry: {}", e))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !options.show_hidden && name.starts_with('.') {
             continue;
         }
+        let metadata = entry
+            .metadata()
+            .map_err(|e| format!("Unable to obtain file information: {}", e))?;
+        items.push(Item {
+            name,
+            path: entry.path(),
+            metadata,
+        });
+    }
+
+    sort_items(&mut items, options);
+    print_items(&items, options);
+    Ok(items)
+}
 
-        if long_format {
-            let metadata = entry
-                .metadata()
-                .map_err(|e| format!("Unable to obtain file information: {}", e))?;
-            let file_type = if path.is_dir() { "d" } else { "-" };
-            let perms = metadata.mode();
-
-            let mode = format!(
-                "{}{}{}{}{}{}{}{}{}",
-                if perms & 0o400 != 0 { "r" } else { "-" },
-                if perms & 0o200 != 0 { "w" } else { "-" },
-                if perms & 0o100 != 0 { "x" } else { "-" },
-                if perms & 0o40 != 0 { "r" } else { "-" },
-                if perms & 0o20 != 0 { "w" } else { "-" },
-                if perms & 0o10 != 0 { "x" } else { "-" },
-                if perms & 0o4 != 0 { "r" } else { "-" },
-                if perms & 0o2 != 0 { "w" } else { "-" },
-                if perms & 0o1 != 0 { "x" } else { "-" },
-            );
-
-            let size = format_size(metadata.len());
-            println!("{}{} {} {}", file_type, mode, size, file_name);
-        } else if path.is_dir() {
-            println!("{}/", file_name);
+fn sort_items(items: &mut [Item], options: &Options) {
+    items.sort_by(|a, b| a.name.cmp(&b.name));
+    // Stable sorts keep names in order between equal keys; newest and largest come first.
+    match options.sort {
+        Sort::Name => {}
+        Sort::Time => {
+            items.sort_by_key(|item| Reverse((item.metadata.mtime(), item.metadata.mtime_nsec())))
+        }
+        Sort::Size => items.sort_by_key(|item| Reverse(item.metadata.len())),
+    }
+    if options.reverse {
+        items.reverse();
+    }
+}
+
+fn print_items(items: &[Item], options: &Options) {
+    let size = |item: &Item| {
+        if options.human {
+            format_size(item.metadata.len())
         } else {
-            println!("{}", file_name);
+            item.metadata.len().to_string()
+        }
+    };
+    let inode_width = items
+        .iter()
+        .map(|item| item.metadata.ino().to_string().len())
+        .max()
+        .unwrap_or(0);
+    let size_width = items.iter().map(|item| size(item).len()).max().unwrap_or(0);
+
+    // Each cell keeps its visible width apart, color sequences take no columns.
+    let mut cells = Vec::with_capacity(items.len());
+    for item in items {
+        let mut prefix = String::new();
+        if options.inode {
+            prefix.push_str(&format!(
+                "{:>width$} ",
+                item.metadata.ino(),
+                width = inode_width
+            ));
         }
+
+        let mut name = match &options.colors {
+            Some(colors) => colors.paint(&item.name, &item.metadata),
+            None => item.name.clone(),
+        };
+        let mut name_width = item.name.chars().count();
+        if options.long_format {
+            if item.metadata.file_type().is_symlink() {
+                let target = fs::read_link(&item.path)
+                    .map(|t| t.display().to_string())
+                    .unwrap_or_default();
+                name.push_str(&format!(" -> {}", target));
+            }
+            // Minutes are precise enough here, `stat` shows the full timestamp.
+            let mtime = format_timestamp(item.metadata.mtime(), 0);
+            prefix.push_str(&format!(
+                "{}{} {:>width$} {} ",
+                file_type_char(&item.metadata.file_type()),
+                format_permissions(item.metadata.mode()),
+                size(item),
+                &mtime[..16],
+                width = size_width
+            ));
+        } else if item.metadata.is_dir() && options.colors.is_none() {
+            name.push('/');
+            name_width += 1;
+        }
+        cells.push((
+            format!("{}{}", prefix, name),
+            prefix.chars().count() + name_width,
+        ));
     }
 
-    Ok(())
+    match options.width {
+        Some(width) => print_columns(&cells, width),
+        None => cells.iter().for_each(|(cell, _)| println!("{}", cell)),
+    }
+}
+
+// Fills columns top to bottom, using as many as fit in `width`.
+fn print_columns(cells: &[(String, usize)], width: usize) {
+    if cells.is_empty() {
+        return;
+    }
+    let mut layout = (cells.len(), vec![0]);
+    for columns in (1..=cells.len()).rev() {
+        let rows = cells.len().div_ceil(columns);
+        let widths: Vec<usize> = cells
+            .chunks(rows)
+            .map(|column| column.iter().map(|(_, w)| *w).max().unwrap_or(0))
+            .collect();
+        let total = widths.iter().sum::<usize>() + COLUMN_GAP * (widths.len() - 1);
+        if total <= width || columns == 1 {
+            layout = (rows, widths);
+            break;
+        }
+    }
+
+    let (rows, widths) = layout;
+    for row in 0..rows {
+        let mut line = String::new();
+        for (column, column_width) in widths.iter().enumerate() {
+            let Some((cell, cell_width)) = cells.get(column * rows + row) else {
+                break;
+            };
+            line.push_str(cell);
+            if column + 1 < widths.len() && cells.get((column + 1) * rows + row).is_some() {
+                line.push_str(&" ".repeat(column_width - cell_width + COLUMN_GAP));
+            }
+        }
+        println!("{}", line);
+    }
 }
 
-fn format_size(size: u64) -> String {
+fn terminal_width() -> usize {
+    let mut size: libc::winsize = unsafe { mem::zeroed() };
+    if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0
+        && size.ws_col > 0
+    {
+        return size.ws_col as usize;
+    }
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+pub fn format_size(size: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
     const GB: u64 = MB * 1024;
@@ -105,3 +404,28 @@ fn format_size(size: u64) -> String {
         format!("{:.1}G", size as f64 / GB as f64)
     }
 }
+
+pub fn file_type_char(file_type: &fs::FileType) -> char {
+    if file_type.is_dir() {
+        'd'
+    } else if file_type.is_symlink() {
+        'l'
+    } else {
+        '-'
+    }
+}
+
+pub fn format_permissions(perms: u32) -> String {
+    format!(
+        "{}{}{}{}{}{}{}{}{}",
+        if perms & 0o400 != 0 { "r" } else { "-" },
+        if perms & 0o200 != 0 { "w" } else { "-" },
+        if perms & 0o100 != 0 { "x" } else { "-" },
+        if perms & 0o40 != 0 { "r" } else { "-" },
+        if perms & 0o20 != 0 { "w" } else { "-" },
+        if perms & 0o10 != 0 { "x" } else { "-" },
+        if perms & 0o4 != 0 { "r" } else { "-" },
+        if perms & 0o2 != 0 { "w" } else { "-" },
+        if perms & 0o1 != 0 { "x" } else { "-" },
+    )
+}
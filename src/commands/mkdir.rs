@@ -34,7 +34,11 @@ pub fn command(args: &[&str]) -> Result<(), String> {
             "-p" | "--parents" => parents = true,
             "-v" | "--verbose" => verbose = true,
             "-m" | "--mode" => {
-                mode = iter.next().map(|s| parse_mode(s)).transpose()?.flatten();
+                mode = iter
+                    .next()
+                    .map(|s| parse_mode(s, 0o777))
+                    .transpose()?
+                    .flatten();
             }
             _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
             _ => directories.push(arg),
@@ -77,14 +81,75 @@ pub fn command(args: &[&str]) -> Result<(), String> {
     Ok(())
 }
 
-fn parse_mode(mode_str: &str) -> Result<Option<u32>, String> {
+/// Parses an octal mode (`755`) or a comma separated list of symbolic clauses
+/// (`u+x,go-w`, `a=rX`) and applies it on top of `current`.
+pub fn parse_mode(mode_str: &str, current: u32) -> Result<Option<u32>, String> {
     if mode_str.is_empty() {
         return Ok(None);
     }
 
-    u32::from_str_radix(mode_str, 8)
-        .map_err(|_| format!("invalid mode '{}'", mode_str))
-        .map(Some)
+    if mode_str.chars().all(|c| c.is_ascii_digit()) {
+        return u32::from_str_radix(mode_str, 8)
+            .ok()
+            .filter(|mode| *mode <= 0o7777)
+            .ok_or_else(|| format!("invalid mode '{}'", mode_str))
+            .map(Some);
+    }
+
+    let invalid = || format!("invalid mode '{}'", mode_str);
+    let is_dir = current & 0o170000 == 0o040000;
+    let mut mode = current & 0o7777;
+
+    for clause in mode_str.split(',') {
+        let op_pos = clause.find(['+', '-', '=']).ok_or_else(invalid)?;
+        let (who, mut rest) = clause.split_at(op_pos);
+
+        let mut who_mask = 0;
+        for c in who.chars() {
+            who_mask |= match c {
+                'u' => 0o4700,
+                'g' => 0o2070,
+                'o' => 0o1007,
+                'a' => 0o7777,
+                _ => return Err(invalid()),
+            };
+        }
+        if who_mask == 0 {
+            who_mask = 0o7777;
+        }
+
+        // A clause may chain several operations, e.g. `u+r-w`.
+        while let Some(op) = rest.chars().next() {
+            rest = &rest[1..];
+            let end = rest.find(['+', '-', '=']).unwrap_or(rest.len());
+            let (perms, tail) = rest.split_at(end);
+            rest = tail;
+
+            let mut bits = 0;
+            for c in perms.chars() {
+                bits |= match c {
+                    'r' => 0o444,
+                    'w' => 0o222,
+                    'x' => 0o111,
+                    'X' if is_dir || mode & 0o111 != 0 => 0o111,
+                    'X' => 0,
+                    's' => 0o6000,
+                    't' => 0o1000,
+                    _ => return Err(invalid()),
+                };
+            }
+            bits &= who_mask;
+
+            match op {
+                '+' => mode |= bits,
+                '-' => mode &= !bits,
+                '=' => mode = (mode & !who_mask) | bits,
+                _ => return Err(invalid()),
+            }
+        }
+    }
+
+    Ok(Some(mode))
 }
 
 fn set_permissions(path: &Path, mode: u32) -> io::Result<()> {
@@ -15,22 +15,52 @@
 pub mod alloc;
 pub mod cat;
 pub mod cd;
+pub mod checksum;
+pub mod chgrp;
+pub mod chmod;
+pub mod chown;
 pub mod cmp;
 pub mod cp;
+pub mod cut;
+pub mod dd;
 pub mod dealloc;
+pub mod df;
+pub mod diff;
+pub mod du;
 pub mod echo;
+pub mod export;
+pub mod find;
 pub mod free;
+pub mod fsbench;
+pub mod grep;
+pub mod head;
 pub mod help;
+pub mod hexdump;
+pub mod ln;
 pub mod ls;
 pub mod mkdir;
 pub mod mount;
+pub mod od;
 pub mod printf;
 pub mod ps;
 pub mod pwd;
+pub mod reader;
+pub mod readlink;
+pub mod realpath;
+pub mod regex;
 pub mod rmdir;
+pub mod sort;
+pub mod stat;
+pub mod tail;
 pub mod touch;
+pub mod tr;
+pub mod tree;
 pub mod truncate;
 pub mod umount;
+pub mod uniq;
+pub mod walk;
+pub mod wc;
+pub mod xxd;
 
 extern crate phf;
 use self::phf::{phf_map, Map};
@@ -44,43 +74,115 @@ pub struct CommandInfo {
 pub static COMMANDS: Map<&'static str, CommandInfo> = phf_map! {
     "cat" => CommandInfo {
         handler: cat::command,
-        description: "Concatenate file(s) to standard output, usage: cat [<path> [<path> [<path> ...]]]",
+        description: "Concatenate file(s) to standard output, usage: cat [-n] [-A] [-s] [<path>|- [<path>|- ...]]",
     },
     "cd" => CommandInfo {
         handler: cd::command,
         description: "Switch current directory, usage: cd <directory>",
     },
+    "chgrp" => CommandInfo {
+        handler: chgrp::command,
+        description: "Change group ownership, usage: chgrp [-R] <gid> <file>...",
+    },
+    "chmod" => CommandInfo {
+        handler: chmod::command,
+        description: "Change file mode bits, usage: chmod [-R] [-v] <mode[,mode]...|octal mode> <file>...",
+    },
+    "chown" => CommandInfo {
+        handler: chown::command,
+        description: "Change file owner and group, usage: chown [-R] <uid>[:<gid>] <file>...",
+    },
     "cmp" => CommandInfo {
         handler: cmp::command,
-        description: "Compare two files byte by byte, usage: cmp <path1> <path2>",
+        description: "Compare two files byte by byte, usage: cmp [-l | -s] [-n <limit>] [-i <skip1>[:<skip2>]] <path1> <path2> [<skip1> [<skip2>]]",
     },
     "cp" => CommandInfo {
         handler: cp::command,
         description: "Copy source to dest, usage: cp <source file> <destination file/dir>",
     },
+    "crc32" => CommandInfo {
+        handler: checksum::crc32,
+        description: "Compute or check CRC-32 checksums, usage: crc32 [-c] <file>...",
+    },
+    "cut" => CommandInfo {
+        handler: cut::command,
+        description: "Print selected fields of each line, usage: cut -f <list> [-d <delimiter>] [-s] [<file>...]",
+    },
+    "dd" => CommandInfo {
+        handler: dd::command,
+        description: "Copy a file block by block, usage: dd [if=<file>] [of=<file>] [bs=<bytes>] [count=<blocks>] [skip=<blocks>] [seek=<blocks>] [conv=notrunc,sync,fsync] [status=progress|noxfer|none]",
+    },
+    "df" => CommandInfo {
+        handler: df::command,
+        description: "Report file system space usage, usage: df [-h] [-T] [<path>...]",
+    },
+    "diff" => CommandInfo {
+        handler: diff::command,
+        description: "Compare files line by line, usage: diff [-q] [-u | -U <lines>] <file1> <file2>",
+    },
+    "du" => CommandInfo {
+        handler: du::command,
+        description: "Estimate file space usage, usage: du [-s] [-h] [-d <depth>] [<path>...]",
+    },
     "echo" => CommandInfo {
         handler: echo::command,
-        description: "Write arguments to the standard output, usage: echo [parameters...] / [>] [file]",
+        description: "Write arguments to the standard output, usage: echo [-n] [-e | -E] [<string>...] [> | >> <file>]",
+    },
+    "export" => CommandInfo {
+        handler: export::command,
+        description: "Set environment variables such as LS_COLORS, or list them, usage: export [<name>=<value>...]",
+    },
+    "find" => CommandInfo {
+        handler: find::command,
+        description: "Search for files in a directory hierarchy, usage: find [<path>...] [-maxdepth <levels>] [-name <pattern>] [-iname <pattern>] [-type f|d|l] [-size [+|-]<n>[cwbkMG]] [-mtime [+|-]<days>] [-print] [-exec <command> [<arg>|{}]... ;]",
     },
     "free" => CommandInfo {
         handler: free::command,
         description: "Display the amount of free and used memory in the system, usage: free",
     },
+    "fsbench" => CommandInfo {
+        handler: fsbench::command,
+        description: "Benchmark file system performance in a directory, usage: fsbench [-s <file size>] [-n <count>] <directory>",
+    },
+    "grep" => CommandInfo {
+        handler: grep::command,
+        description: "Print lines matching a pattern, usage: grep [-i] [-v] [-n] [-c] [-r] [-l] [-E] <pattern> [<file>...]",
+    },
+    "head" => CommandInfo {
+        handler: head::command,
+        description: "Output the first part of files or standard input, usage: head [-n <lines>|-c <bytes>] [<file>...]",
+    },
     "help" => CommandInfo {
         handler: help::command,
         description: "Use help [command] view help for a specific command",
     },
+    "hexdump" => CommandInfo {
+        handler: hexdump::command,
+        description: "Display file contents in hexadecimal and ASCII, usage: hexdump [-C] [-v] [-s <offset>] [-n <length>] [<file>]",
+    },
+    "ln" => CommandInfo {
+        handler: ln::command,
+        description: "Make links between files, usage: ln [-s] [-f] [-v] <target>... [<link name>|<directory>]",
+    },
     "ls" => CommandInfo {
         handler: ls::command,
-        description: "List directory contents, usage: ls [-a] [-l] [directory]",
+        description: "List directory contents, usage: ls [-a] [-l] [-R] [-t | -S] [-r] [-h] [-i] [-d] [-1] [--color[=always|never|auto]] [<path>...]",
+    },
+    "md5sum" => CommandInfo {
+        handler: checksum::md5sum,
+        description: "Compute or check MD5 checksums, usage: md5sum [-c] <file>...",
     },
     "mkdir" => CommandInfo {
         handler: mkdir::command,
         description: "Create directory, usage: mkdir [OPTION] <path>",
     },
+    "od" => CommandInfo {
+        handler: od::command,
+        description: "Dump files in octal and other formats, usage: od [-A d|o|x|n] [-t <type>]... [-bcdox] [-v] [-j <skip>] [-N <length>] [<file>...]",
+    },
     "printf" => CommandInfo {
         handler: printf::command,
-        description: "Formats and prints args under control of the format, usage: printf string<%s, %d, %f> [arg...]",
+        description: "Formats and prints args under control of the format, usage: printf <format> [<argument>...]",
     },
     "ps" => CommandInfo {
         handler: ps::command,
@@ -90,25 +192,77 @@ pub static COMMANDS: Map<&'static str, CommandInfo> = phf_map! {
         handler: pwd::command,
         description: "Print the current working directory",
     },
+    "readlink" => CommandInfo {
+        handler: readlink::command,
+        description: "Print the target of a symbolic link, usage: readlink [-f] <path>...",
+    },
+    "realpath" => CommandInfo {
+        handler: realpath::command,
+        description: "Print the resolved absolute path, usage: realpath <path>...",
+    },
     "rmdir" => CommandInfo {
         handler: rmdir::command,
         description: "rmdir, Usage: rmdir <path1> <path2>",
     },
+    "sha1sum" => CommandInfo {
+        handler: checksum::sha1sum,
+        description: "Compute or check SHA-1 checksums, usage: sha1sum [-c] <file>...",
+    },
+    "sha256sum" => CommandInfo {
+        handler: checksum::sha256sum,
+        description: "Compute or check SHA-256 checksums, usage: sha256sum [-c] <file>...",
+    },
+    "sort" => CommandInfo {
+        handler: sort::command,
+        description: "Sort lines of text, usage: sort [-n] [-r] [-u] [-t <separator>] [-k <field>[,<field>]] [<file>...]",
+    },
+    "stat" => CommandInfo {
+        handler: stat::command,
+        description: "Display file status, usage: stat [-L] [-c FORMAT] <file>...",
+    },
+    "tail" => CommandInfo {
+        handler: tail::command,
+        description: "Output the last part of files or standard input, usage: tail [-f] [-n [+]<lines>|-c [+]<bytes>] [<file>...]",
+    },
     "touch" => CommandInfo {
         handler: touch::command,
-        description: "Update the access and modification times of each file to the current time, usage: touch <file>",
+        description: "Update the access and modification times of each file, usage: touch [-a] [-m] [-c] [-d <time>] [-r <ref file>] <file>...",
+    },
+    "tr" => CommandInfo {
+        handler: tr::command,
+        description: "Translate, squeeze or delete characters from standard input, usage: tr [-d] [-s] <set1> [<set2>]",
+    },
+    "tree" => CommandInfo {
+        handler: tree::command,
+        description: "List contents of directories in a tree-like format, usage: tree [-a] [-d] [-h] [-L <level>] [<path>...]",
     },
     "truncate" => CommandInfo {
         handler: truncate::command,
-        description: "Shrink or extend the size of each file, usage: truncate <file> <size>",
+        description: "Shrink or extend the size of each file, usage: truncate [-c] -s [+|-|<|>|/|%]<size>[K|M|G|T][iB|B] | -r <rfile> <file>... / truncate <file> <size>",
+    },
+    "uniq" => CommandInfo {
+        handler: uniq::command,
+        description: "Report or omit repeated adjacent lines, usage: uniq [-c] [-d] [-u] [<file>]",
+    },
+    "unset" => CommandInfo {
+        handler: export::unset,
+        description: "Remove environment variables, usage: unset <name>...",
+    },
+    "wc" => CommandInfo {
+        handler: wc::command,
+        description: "Print line, word and byte counts, usage: wc [-l] [-w] [-c] [<file>...]",
     },
     "mount" => CommandInfo {
         handler: mount::command,
-        description: "Mount a filesystem, usage: mount <path> <fstype(only support tmpfs)>",
+        description: "Mount a filesystem or list mounts, usage: mount [-a [-T <fstab>] | -t <fstype> [-o <options>] <source> <target>] / mount <path> <fstype>",
     },
     "umount" => CommandInfo {
         handler: umount::command,
-        description: "Unmount filesystems, usage: umout <path>",
+        description: "Unmount filesystems, usage: umount <path>",
+    },
+    "xxd" => CommandInfo {
+        handler: xxd::command,
+        description: "Make a hex dump or reverse it to binary, usage: xxd [-p] [-s <offset>] [-l <length>] [<infile> [<outfile>]] / xxd -r [-p] [<infile> [<outfile>]]",
     },
     "alloc" => CommandInfo {
         handler: crate::commands::alloc::command,
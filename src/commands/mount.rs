@@ -13,29 +13,365 @@
 // limitations under the License.
 
 use librs::direct;
-use std::{ffi::CString, ptr};
+use std::{ffi::CString, fs, ptr};
+
+const MOUNTS_PATH: &str = "/proc/mounts";
+const FSTAB_PATH: &str = "/etc/fstab";
+const USAGE: &str = "Usage: mount [-a [-T <fstab>] | -t <fstype> [-o <options>] <source> <target>] / mount <path> <fstype>";
+
+/// One mount described by a line of the fstab.
+struct FstabEntry<'a> {
+    source: &'a str,
+    target: &'a str,
+    fs_type: &'a str,
+    options: Vec<&'a str>,
+}
+
+/// One line of the kernel mount table.
+pub struct MountEntry {
+    pub source: String,
+    pub target: String,
+    pub fs_type: String,
+    pub options: String,
+}
 
 // std not support, call librs
 pub fn command(args: &[&str]) -> Result<(), String> {
-    if args.len() != 2 {
-        return Err("Usage: mount <path> <fstype>".to_string());
+    if args.is_empty() {
+        for mount in read_mounts()? {
+            println!(
+                "{} on {} type {} ({})",
+                mount.source, mount.target, mount.fs_type, mount.options
+            );
+        }
+        return Ok(());
+    }
+
+    let mut fs_type = None;
+    let mut options = Vec::new();
+    let mut all = false;
+    let mut fstab = FSTAB_PATH;
+    let mut operands = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-a" => all = true,
+            "-T" => fstab = iter.next().ok_or("option requires an argument -- 'T'")?,
+            "-t" => fs_type = Some(*iter.next().ok_or("option requires an argument -- 't'")?),
+            "-o" => options.extend(
+                iter.next()
+                    .ok_or("option requires an argument -- 'o'")?
+                    .split(','),
+            ),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => operands.push(*arg),
+        }
+    }
+    if all {
+        // Everything comes from the fstab, a type or operands would be silently ignored.
+        if fs_type.is_some() || !options.is_empty() || !operands.is_empty() {
+            return Err(USAGE.to_string());
+        }
+        return mount_all(fstab);
+    }
+    let (source, target, fs_type) = match (fs_type, operands.as_slice()) {
+        (Some(fs_type), [source, target]) => (Some(*source), *target, fs_type),
+        // `mount <path> <fstype>` is the original syntax, existing scripts still use it.
+        (None, [target, fs_type]) => (None, *target, *fs_type),
+        _ => return Err(USAGE.to_string()),
+    };
+    mount(source, target, fs_type, &options)
+}
+
+/// Mounts the entries of the board fstab before the first prompt, if there is one.
+pub fn automount() {
+    if fs::metadata(FSTAB_PATH).is_err() {
+        return;
+    }
+    if let Err(e) = mount_all(FSTAB_PATH) {
+        if !e.is_empty() {
+            println!("mount: {}", e);
+        }
+    }
+}
+
+// Every entry is attempted, failures are reported one by one and only fail the whole at the end.
+fn mount_all(fstab: &str) -> Result<(), String> {
+    let content =
+        fs::read_to_string(fstab).map_err(|e| format!("Failed to read {}: {}", fstab, e))?;
+    // Missing until procfs is mounted, which may be one of the entries below.
+    let mut mounted = read_mounts().ok();
+    let mut failed = false;
+
+    for (number, line) in content.lines().enumerate() {
+        let entry = match parse_fstab_line(line) {
+            Ok(Some(entry)) => entry,
+            Ok(None) => continue,
+            Err(e) => {
+                println!("mount: {}:{}: {}", fstab, number + 1, e);
+                failed = true;
+                continue;
+            }
+        };
+        let present = mounted
+            .as_ref()
+            .is_some_and(|mounts| mounts.iter().any(|m| m.target == entry.target));
+        if entry.options.contains(&"noauto") || present {
+            continue;
+        }
+        let result = match call_mount(
+            Some(entry.source),
+            entry.target,
+            entry.fs_type,
+            &entry.options,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                println!("mount: {}", e);
+                failed = true;
+                continue;
+            }
+        };
+        match result {
+            0 => mounted = read_mounts().ok(),
+            // Without a mount table EBUSY is the only sign the target is mounted already.
+            _ if mounted.is_none() && result.abs() == libc::EBUSY => {}
+            _ => {
+                println!(
+                    "mount: {}",
+                    mount_error(Some(entry.source), entry.target, entry.fs_type, result)
+                );
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        return Err(String::new());
+    }
+    Ok(())
+}
+
+// Blank lines and `#` comments give `None`. The dump and pass fields are accepted and ignored.
+fn parse_fstab_line(line: &str) -> Result<Option<FstabEntry<'_>>, String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
     }
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let (source, target, fs_type, options) = match fields.as_slice() {
+        [source, target, fs_type] => (*source, *target, *fs_type, "defaults"),
+        [source, target, fs_type, options, ..] if fields.len() <= 6 => {
+            (*source, *target, *fs_type, *options)
+        }
+        _ => return Err("expected <source> <target> <type> [<options>]".to_string()),
+    };
+    Ok(Some(FstabEntry {
+        source,
+        target,
+        fs_type,
+        options: options.split(',').collect(),
+    }))
+}
+
+// File systems without a backing device, such as tmpfs, may be mounted without a source.
+fn mount(
+    source: Option<&str>,
+    target: &str,
+    fs_type: &str,
+    options: &[&str],
+) -> Result<(), String> {
+    match call_mount(source, target, fs_type, options)? {
+        0 => Ok(()),
+        result => Err(mount_error(source, target, fs_type, result)),
+    }
+}
 
-    let target = CString::new(args[0]).map_err(|e| e.to_string())?;
-    let fs_type = CString::new(args[1]).map_err(|e| e.to_string())?;
-    let result = unsafe {
+// Returns what `direct::mount` returned, errors only when the arguments cannot be passed.
+fn call_mount(
+    source: Option<&str>,
+    target: &str,
+    fs_type: &str,
+    options: &[&str],
+) -> Result<libc::c_int, String> {
+    let (flags, data) = parse_options(options);
+
+    let c_source = source
+        .map(CString::new)
+        .transpose()
+        .map_err(|e| e.to_string())?;
+    let c_target = CString::new(target).map_err(|e| e.to_string())?;
+    let c_fs_type = CString::new(fs_type).map_err(|e| e.to_string())?;
+    let data = CString::new(data).map_err(|e| e.to_string())?;
+    // Drivers without options get no data at all, as before.
+    let data_ptr = if data.is_empty() {
+        ptr::null()
+    } else {
+        data.as_ptr().cast()
+    };
+    Ok(unsafe {
         direct::mount(
-            ptr::null(),
-            target.as_ptr(),
-            fs_type.as_ptr(),
-            0,
-            ptr::null(),
+            c_source
+                .as_ref()
+                .map_or(ptr::null(), |source| source.as_ptr()),
+            c_target.as_ptr(),
+            c_fs_type.as_ptr(),
+            flags,
+            data_ptr,
         )
+    })
+}
+
+fn mount_error(source: Option<&str>, target: &str, fs_type: &str, result: libc::c_int) -> String {
+    format!(
+        "mounting '{}' on '{}' failed: {}",
+        source.unwrap_or(fs_type),
+        target,
+        describe_error(result)
+    )
+}
+
+/// Names the errno behind a failed librs call, `direct` calls return the negated errno.
+pub fn describe_error(result: libc::c_int) -> String {
+    let code = result.abs();
+    let (name, message) = match code {
+        libc::EPERM => ("EPERM", "Operation not permitted"),
+        libc::ENOENT => ("ENOENT", "No such file or directory"),
+        libc::EIO => ("EIO", "Input/output error"),
+        libc::ENXIO => ("ENXIO", "No such device or address"),
+        libc::ENOMEM => ("ENOMEM", "Cannot allocate memory"),
+        libc::EACCES => ("EACCES", "Permission denied"),
+        libc::EFAULT => ("EFAULT", "Bad address"),
+        libc::ENOTBLK => ("ENOTBLK", "Block device required"),
+        libc::EBUSY => ("EBUSY", "Device or resource busy"),
+        libc::EEXIST => ("EEXIST", "File exists"),
+        libc::ENODEV => ("ENODEV", "No such device"),
+        libc::ENOTDIR => ("ENOTDIR", "Not a directory"),
+        libc::EINVAL => ("EINVAL", "Invalid argument"),
+        libc::EMFILE => ("EMFILE", "Too many open files"),
+        libc::ENOSPC => ("ENOSPC", "No space left on device"),
+        libc::EROFS => ("EROFS", "Read-only file system"),
+        libc::ENAMETOOLONG => ("ENAMETOOLONG", "File name too long"),
+        libc::ENOSYS => ("ENOSYS", "Function not implemented"),
+        libc::ELOOP => ("ELOOP", "Too many levels of symbolic links"),
+        _ => return format!("error code {}", code),
     };
+    format!("{} ({})", name, message)
+}
 
-    if result != 0 {
-        println!("mount failed (error code: {})", result);
+// Generic options become mount flags, the rest is handed to the file system.
+fn parse_options(options: &[&str]) -> (libc::c_ulong, String) {
+    let mut flags = 0;
+    let mut data = Vec::new();
+    for option in options {
+        match *option {
+            "" | "defaults" | "rw" | "auto" | "noauto" => {}
+            "ro" => flags |= libc::MS_RDONLY,
+            "nosuid" => flags |= libc::MS_NOSUID,
+            "nodev" => flags |= libc::MS_NODEV,
+            "noexec" => flags |= libc::MS_NOEXEC,
+            "sync" => flags |= libc::MS_SYNCHRONOUS,
+            "remount" => flags |= libc::MS_REMOUNT,
+            "noatime" => flags |= libc::MS_NOATIME,
+            "nodiratime" => flags |= libc::MS_NODIRATIME,
+            "relatime" => flags |= libc::MS_RELATIME,
+            _ => data.push(*option),
+        }
     }
+    (flags, data.join(","))
+}
 
-    Ok(())
+pub fn read_mounts() -> Result<Vec<MountEntry>, String> {
+    let content = fs::read_to_string(MOUNTS_PATH)
+        .map_err(|e| format!("Failed to read {}: {}", MOUNTS_PATH, e))?;
+    Ok(parse_mounts(&content))
+}
+
+// Lines too short to name a source, target and type are skipped.
+fn parse_mounts(content: &str) -> Vec<MountEntry> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some(MountEntry {
+                source: fields.next()?.to_string(),
+                target: fields.next()?.to_string(),
+                fs_type: fields.next()?.to_string(),
+                options: fields.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_split_into_flags_and_data() {
+        let (flags, data) = parse_options(&["ro", "noexec", "size=1m", "mode=755"]);
+        assert_eq!(flags, libc::MS_RDONLY | libc::MS_NOEXEC);
+        assert_eq!(data, "size=1m,mode=755");
+    }
+
+    #[test]
+    fn default_options_are_dropped() {
+        let (flags, data) = parse_options(&["defaults", "rw", "auto", "noauto", ""]);
+        assert_eq!(flags, 0);
+        assert_eq!(data, "");
+    }
+
+    #[test]
+    fn mount_table_lines() {
+        let mounts = parse_mounts(
+            "tmpfs /tmp tmpfs rw,size=1m 0 0\n\
+             /dev/vda /data fatfs rw 0 0\n\
+             proc /proc procfs\n\
+             \n\
+             broken line\n",
+        );
+        let fields: Vec<_> = mounts
+            .iter()
+            .map(|m| (&*m.source, &*m.target, &*m.fs_type, &*m.options))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("tmpfs", "/tmp", "tmpfs", "rw,size=1m"),
+                ("/dev/vda", "/data", "fatfs", "rw"),
+                ("proc", "/proc", "procfs", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn fstab_fields() {
+        let entry = parse_fstab_line("  /dev/vda /data fatfs ro,noexec,uid=0 0 2")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (entry.source, entry.target, entry.fs_type),
+            ("/dev/vda", "/data", "fatfs")
+        );
+        assert_eq!(entry.options, ["ro", "noexec", "uid=0"]);
+    }
+
+    #[test]
+    fn fstab_options_default() {
+        let entry = parse_fstab_line("tmpfs /tmp tmpfs").unwrap().unwrap();
+        assert_eq!(entry.options, ["defaults"]);
+    }
+
+    #[test]
+    fn fstab_skips_blank_and_comment_lines() {
+        assert!(parse_fstab_line("").unwrap().is_none());
+        assert!(parse_fstab_line("   \t").unwrap().is_none());
+        assert!(parse_fstab_line("# tmpfs /tmp tmpfs").unwrap().is_none());
+    }
+
+    #[test]
+    fn fstab_rejects_wrong_field_count() {
+        assert!(parse_fstab_line("tmpfs /tmp").is_err());
+        assert!(parse_fstab_line("tmpfs /tmp tmpfs defaults 0 0 extra").is_err());
+    }
 }
@@ -0,0 +1,334 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    hexdump::printable,
+    reader::{self, open_at, parse_offset},
+};
+use std::io::{self, Read, Write};
+
+const BYTES_PER_LINE: usize = 16;
+
+#[derive(Clone, Copy)]
+enum Radix {
+    Octal,
+    Decimal,
+    Hex,
+    None,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Char,
+    Signed,
+    Unsigned,
+    Octal,
+    Hex,
+}
+
+/// One output line per type, `-t x1z` is `Format { kind: Hex, size: 1, text: true }`.
+#[derive(Clone, Copy)]
+struct Format {
+    kind: Kind,
+    size: usize,
+    // Append the bytes as text between `>` and `<`.
+    text: bool,
+}
+
+impl Format {
+    fn new(kind: Kind, size: usize) -> Format {
+        Format {
+            kind,
+            size,
+            text: false,
+        }
+    }
+
+    // Room for a full line of values, each with its separating space.
+    fn line_width(&self) -> usize {
+        (self.digits() + 1) * (BYTES_PER_LINE / self.size)
+    }
+
+    // Widest value this format can print, without the separating space.
+    fn digits(&self) -> usize {
+        match (self.kind, self.size) {
+            (Kind::Char, _) => 3,
+            (Kind::Hex, size) => size * 2,
+            (Kind::Octal, 1) => 3,
+            (Kind::Octal, 2) => 6,
+            (Kind::Octal, 4) => 11,
+            (Kind::Octal, _) => 22,
+            (Kind::Unsigned, 1) => 3,
+            (Kind::Unsigned, 2) => 5,
+            (Kind::Unsigned, 4) => 10,
+            (Kind::Unsigned, _) => 20,
+            (Kind::Signed, 1) => 4,
+            (Kind::Signed, 2) => 6,
+            (Kind::Signed, 4) => 11,
+            (Kind::Signed, _) => 20,
+        }
+    }
+
+    fn render(&self, unit: &[u8]) -> String {
+        let mut bytes = [0u8; 8];
+        bytes[..unit.len()].copy_from_slice(unit);
+        let value = match self.size {
+            1 => bytes[0] as u64,
+            2 => u16::from_ne_bytes([bytes[0], bytes[1]]) as u64,
+            4 => u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64,
+            _ => u64::from_ne_bytes(bytes),
+        };
+        let width = self.digits();
+        match self.kind {
+            Kind::Char => char_name(bytes[0]),
+            Kind::Hex => format!("{:0width$x}", value),
+            Kind::Octal => format!("{:0width$o}", value),
+            Kind::Unsigned => value.to_string(),
+            Kind::Signed => match self.size {
+                1 => (value as u8 as i8).to_string(),
+                2 => (value as u16 as i16).to_string(),
+                4 => (value as u32 as i32).to_string(),
+                _ => (value as i64).to_string(),
+            },
+        }
+    }
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut radix = Radix::Octal;
+    let mut formats = Vec::new();
+    let mut skip = 0;
+    let mut length = None;
+    let mut no_squeeze = false;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-A" => {
+                radix = match *iter.next().ok_or("option requires an argument -- 'A'")? {
+                    "o" => Radix::Octal,
+                    "d" => Radix::Decimal,
+                    "x" => Radix::Hex,
+                    "n" => Radix::None,
+                    other => return Err(format!("invalid output address radix '{}'", other)),
+                }
+            }
+            "-t" => formats.extend(parse_type(
+                iter.next().ok_or("option requires an argument -- 't'")?,
+            )?),
+            "-j" => skip = parse_offset(iter.next().ok_or("option requires an argument -- 'j'")?)?,
+            "-N" => {
+                length = Some(parse_offset(
+                    iter.next().ok_or("option requires an argument -- 'N'")?,
+                )?)
+            }
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                for flag in arg[1..].chars() {
+                    formats.push(match flag {
+                        'b' => Format::new(Kind::Octal, 1),
+                        'c' => Format::new(Kind::Char, 1),
+                        'd' => Format::new(Kind::Unsigned, 2),
+                        'o' => Format::new(Kind::Octal, 2),
+                        'x' => Format::new(Kind::Hex, 2),
+                        'v' => {
+                            no_squeeze = true;
+                            continue;
+                        }
+                        _ => return Err(format!("invalid option -- '{}'", flag)),
+                    });
+                }
+            }
+            _ => files.push(*arg),
+        }
+    }
+    if formats.is_empty() {
+        formats.push(Format::new(Kind::Octal, 2));
+    }
+
+    let files = reader::inputs(&files);
+    let input = open_inputs(&files, skip)?;
+    let mut input: Box<dyn Read> = match length {
+        Some(length) => Box::new(input.take(length)),
+        None => input,
+    };
+
+    // Every type gets the width of the widest line so the lines stay aligned.
+    let line_width = formats
+        .iter()
+        .map(|format| format.line_width())
+        .max()
+        .unwrap_or(0);
+
+    let mut out = io::stdout().lock();
+    let mut offset = skip;
+    let mut previous: Option<[u8; BYTES_PER_LINE]> = None;
+    let mut squeezing = false;
+    let mut line = [0u8; BYTES_PER_LINE];
+
+    loop {
+        let read = reader::read_full(&mut input, &mut line).map_err(|e| e.to_string())?;
+        if read == 0 {
+            break;
+        }
+
+        // Runs of identical full lines collapse into a single `*`.
+        if !no_squeeze && read == BYTES_PER_LINE && previous == Some(line) {
+            if !squeezing {
+                writeln!(out, "*").map_err(|e| e.to_string())?;
+                squeezing = true;
+            }
+        } else {
+            squeezing = false;
+            let address = format_address(radix, offset);
+            for (i, format) in formats.iter().enumerate() {
+                let lead = if i == 0 {
+                    address.clone()
+                } else {
+                    " ".repeat(address.len())
+                };
+                write_line(&mut out, &lead, format, line_width, &line[..read])
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        previous = (read == BYTES_PER_LINE).then_some(line);
+        offset += read as u64;
+    }
+
+    if !matches!(radix, Radix::None) {
+        writeln!(out, "{}", format_address(radix, offset)).map_err(|e| e.to_string())?;
+    }
+    out.flush().map_err(|e| e.to_string())
+}
+
+// Parses a `-t` argument such as `x1`, `d4`, `c` or several run together as in `x2c`.
+fn parse_type(spec: &str) -> Result<Vec<Format>, String> {
+    let invalid = || format!("invalid type string '{}'", spec);
+    let chars: Vec<char> = spec.chars().collect();
+    let mut formats = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let kind = match chars[i] {
+            'c' => Kind::Char,
+            'd' => Kind::Signed,
+            'u' => Kind::Unsigned,
+            'o' => Kind::Octal,
+            'x' => Kind::Hex,
+            _ => return Err(invalid()),
+        };
+        i += 1;
+
+        let mut size = 1;
+        if kind != Kind::Char {
+            let digits: String = chars[i..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            i += digits.len();
+            size = match (digits.as_str(), chars.get(i)) {
+                ("", Some('C')) => 1,
+                ("", Some('S')) => 2,
+                ("", Some('I')) => 4,
+                ("", Some('L')) => 8,
+                ("", _) => 4,
+                ("1", _) => 1,
+                ("2", _) => 2,
+                ("4", _) => 4,
+                ("8", _) => 8,
+                _ => return Err(invalid()),
+            };
+            if digits.is_empty() && matches!(chars.get(i), Some('C' | 'S' | 'I' | 'L')) {
+                i += 1;
+            }
+        }
+
+        let mut format = Format::new(kind, size);
+        if chars.get(i) == Some(&'z') {
+            format.text = true;
+            i += 1;
+        }
+        formats.push(format);
+    }
+
+    Ok(formats)
+}
+
+// Operands are read back to back as one stream, `-j` skips into that stream.
+fn open_inputs(files: &[&str], skip: u64) -> Result<Box<dyn Read>, String> {
+    if let [name] = files {
+        return Ok(open_at(name, skip)?);
+    }
+    let mut input: Box<dyn Read> = Box::new(io::empty());
+    for name in files {
+        input = Box::new(input.chain(reader::open(name)?));
+    }
+    let skipped =
+        io::copy(&mut (&mut input).take(skip), &mut io::sink()).map_err(|e| e.to_string())?;
+    if skipped < skip {
+        return Err("cannot skip past end of combined input".to_string());
+    }
+    Ok(input)
+}
+
+fn format_address(radix: Radix, offset: u64) -> String {
+    match radix {
+        Radix::Octal => format!("{:07o}", offset),
+        Radix::Decimal => format!("{:07}", offset),
+        Radix::Hex => format!("{:06x}", offset),
+        Radix::None => String::new(),
+    }
+}
+
+fn write_line(
+    out: &mut dyn Write,
+    lead: &str,
+    format: &Format,
+    line_width: usize,
+    bytes: &[u8],
+) -> io::Result<()> {
+    let fields = BYTES_PER_LINE / format.size;
+    let pad = line_width - format.line_width();
+    write!(out, "{}", lead)?;
+    // The padding is spread over the fields the way od does. A short last
+    // unit is filled up with zeros.
+    for (i, unit) in bytes.chunks(format.size).enumerate() {
+        let extra = pad * (fields - i) / fields - pad * (fields - i - 1) / fields;
+        let width = format.digits() + 1 + extra;
+        write!(out, "{:>width$}", format.render(unit))?;
+    }
+    if format.text {
+        let blank = fields - bytes.len().div_ceil(format.size);
+        let missing = blank * (format.digits() + 1) + pad * blank / fields;
+        write!(out, "{:missing$}  >{}<", "", printable(bytes))?;
+    }
+    writeln!(out)
+}
+
+fn char_name(byte: u8) -> String {
+    match byte {
+        0 => "\\0".to_string(),
+        7 => "\\a".to_string(),
+        8 => "\\b".to_string(),
+        b'\t' => "\\t".to_string(),
+        b'\n' => "\\n".to_string(),
+        11 => "\\v".to_string(),
+        12 => "\\f".to_string(),
+        b'\r' => "\\r".to_string(),
+        _ if byte.is_ascii_graphic() || byte == b' ' => (byte as char).to_string(),
+        _ => format!("{:03o}", byte),
+    }
+}
@@ -12,93 +12,590 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::io::{self, Write};
+
 pub fn command(args: &[&str]) -> Result<(), String> {
     if args.is_empty() {
-        return Err("Usage: printf string<%s, %d, %f> [arg...]".to_string());
+        return Err("Usage: printf <format> [<argument>...]".to_string());
     }
     let full_input = args.join(" ");
-    let (format_str, arguments) = split_format_and_args(&full_input)?;
-    match format_string(&format_str, arguments.as_slice()) {
-        Ok(s) => println!("Formatted: {}", s),
-        Err(e) => println!("Error: {}", e),
+    let mut tokens = split_format_and_args(&full_input).into_iter();
+    let format = tokens.next().unwrap_or_default();
+    let arguments: Vec<String> = tokens.collect();
+
+    let mut printer = Printer {
+        arguments: &arguments,
+        next: 0,
+        out: Vec::new(),
+        error: None,
+    };
+    // The format is reused while arguments remain, as long as it consumes some.
+    loop {
+        let start = printer.next;
+        match printer.format(format.as_bytes()) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => {
+                printer.error = Some(e);
+                break;
+            }
+        }
+        if printer.next == start || printer.next >= arguments.len() {
+            break;
+        }
+    }
+
+    let mut stdout = io::stdout().lock();
+    stdout
+        .write_all(&printer.out)
+        .and_then(|_| stdout.flush())
+        .map_err(|e| e.to_string())?;
+    match printer.error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+// The shell splits on whitespace only, so quotes are resolved here: a quoted
+// run of words is a single argument.
+fn split_format_and_args(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quote = None;
+    let mut escaped = false;
+    for c in input.chars() {
+        // `\"` stays in the token for the escape expansion to turn into a quote.
+        if escaped {
+            current.push(c);
+            escaped = false;
+            continue;
+        }
+        match (quote, c) {
+            (_, '\\') => {
+                current.push(c);
+                escaped = true;
+                in_token = true;
+            }
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_token = true;
+            }
+            (Some(q), _) if c == q => quote = None,
+            (None, ' ') => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            _ => {
+                current.push(c);
+                in_token = true;
+            }
+        }
     }
-    Ok(())
+    if in_token {
+        tokens.push(current);
+    }
+    tokens
 }
 
-fn split_format_and_args(input: &str) -> Result<(String, Vec<String>), String> {
-    let mut chars = input.chars();
-    let mut format_str = String::new();
-    let mut arguments = String::new();
-    let mut in_quotes = false;
-    let mut args = false;
-    for c in chars {
-        match c {
-            '"' if !in_quotes && !args => {
-                in_quotes = true;
-            }
-            '"' if in_quotes && !args => {
-                args = true;
-            }
-            _ if in_quotes && !args => format_str.push(c),
-            _ if args => arguments.push(c),
-            _ => {}
-        }
-    }
-    let args_vec: Vec<String> = arguments
-        .split_whitespace()
-        .map(|s| s.to_string())
-        .collect();
-    Ok((format_str, args_vec))
+/// Expands backslash escapes the way `printf` does. With `octal_zero`, octal
+/// escapes are written `\0NNN` as in `%b` and `echo -e`, otherwise `\NNN`.
+/// The flag is false when a `\c` asked to stop all further output.
+pub fn expand_escapes(text: &[u8], octal_zero: bool) -> Result<(Vec<u8>, bool), String> {
+    let mut out = Vec::with_capacity(text.len());
+    let mut i = 0;
+    while i < text.len() {
+        if text[i] != b'\\' || i + 1 == text.len() {
+            out.push(text[i]);
+            i += 1;
+            continue;
+        }
+        let (byte, length) = match text[i + 1] {
+            b'x' => {
+                let digits = count_digits(&text[i + 2..], 16, 2);
+                if digits == 0 {
+                    return Err("missing hexadecimal number in escape".to_string());
+                }
+                (parse_digits(&text[i + 2..i + 2 + digits], 16), 2 + digits)
+            }
+            b'0'..=b'7' => {
+                let skip = usize::from(octal_zero && text[i + 1] == b'0');
+                let start = i + 1 + skip;
+                let digits = count_digits(&text[start..], 8, 3);
+                (
+                    parse_digits(&text[start..start + digits], 8),
+                    1 + skip + digits,
+                )
+            }
+            b'c' => return Ok((out, false)),
+            b'\\' => (b'\\', 2),
+            b'"' => (b'"', 2),
+            b'a' => (0x07, 2),
+            b'b' => (0x08, 2),
+            b'e' => (0x1b, 2),
+            b'f' => (0x0c, 2),
+            b'n' => (b'\n', 2),
+            b'r' => (b'\r', 2),
+            b't' => (b'\t', 2),
+            b'v' => (0x0b, 2),
+            _ => (b'\\', 1),
+        };
+        out.push(byte);
+        i += length;
+    }
+    Ok((out, true))
 }
 
-fn format_string(format_str: &str, arguments: &[String]) -> Result<String, String> {
-    let mut result = String::new();
-    let mut arg_index = 0;
-    let mut chars = format_str.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        if c == '%' {
-            let specifier = chars.next().ok_or("Incomplete format specifier")?;
-            if arg_index >= arguments.len() {
-                return Err(format!("Missing argument for %{}", specifier));
-            }
-
-            match specifier {
-                'd' | 'i' => {
-                    if arguments[arg_index].parse::<i64>().is_err() {
-                        return Err(format!(
-                            "Expected integer for %{}, got '{}'",
-                            specifier, arguments[arg_index]
-                        ));
+fn count_digits(text: &[u8], radix: u32, max: usize) -> usize {
+    text.iter()
+        .take(max)
+        .take_while(|b| (**b as char).is_digit(radix))
+        .count()
+}
+
+// Values wrap to a byte like C's putchar, e.g. `\777`.
+fn parse_digits(digits: &[u8], radix: u32) -> u8 {
+    digits.iter().fold(0u32, |value, b| {
+        value * radix + (*b as char).to_digit(radix).unwrap_or(0)
+    }) as u8
+}
+
+#[derive(Default)]
+struct Spec {
+    left: bool,
+    plus: bool,
+    space: bool,
+    alternate: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+}
+
+struct Printer<'a> {
+    arguments: &'a [String],
+    next: usize,
+    out: Vec<u8>,
+    // The first conversion error, printing carries on like coreutils does.
+    error: Option<String>,
+}
+
+impl Printer<'_> {
+    // Returns false when `\c` ended the output.
+    fn format(&mut self, format: &[u8]) -> Result<bool, String> {
+        let mut i = 0;
+        while i < format.len() {
+            match format[i] {
+                b'\\' => {
+                    let end = escape_end(format, i);
+                    let (bytes, keep_going) = expand_escapes(&format[i..end], false)?;
+                    self.out.extend_from_slice(&bytes);
+                    if !keep_going {
+                        return Ok(false);
                     }
-                    result.push_str(&arguments[arg_index]);
+                    i = end;
                 }
-                'f' => {
-                    if arguments[arg_index].parse::<f64>().is_err() {
-                        return Err(format!(
-                            "Expected float for %{}, got '{}'",
-                            specifier, arguments[arg_index]
-                        ));
+                b'%' => {
+                    let start = i;
+                    i += 1;
+                    match format.get(i) {
+                        Some(b'%') => {
+                            self.out.push(b'%');
+                            i += 1;
+                            continue;
+                        }
+                        // %b takes no flags, width or precision.
+                        Some(b'b') => {
+                            i += 1;
+                            let argument = self.next_argument();
+                            let (bytes, keep_going) = expand_escapes(argument.as_bytes(), true)?;
+                            self.out.extend_from_slice(&bytes);
+                            if !keep_going {
+                                return Ok(false);
+                            }
+                            continue;
+                        }
+                        _ => {}
+                    }
+
+                    // Flags and precision narrow the conversions they make sense for.
+                    let mut allowed = String::from("cdeEfFgGiosuxX");
+                    let mut spec = Spec::default();
+                    while let Some(flag) = format.get(i) {
+                        match flag {
+                            b'-' => spec.left = true,
+                            b'+' => spec.plus = true,
+                            b' ' => spec.space = true,
+                            b'#' => {
+                                spec.alternate = true;
+                                allowed.retain(|c| !"cdisu".contains(c));
+                            }
+                            b'0' => {
+                                spec.zero = true;
+                                allowed.retain(|c| !"cs".contains(c));
+                            }
+                            _ => break,
+                        }
+                        i += 1;
                     }
-                    result.push_str(&arguments[arg_index]);
+                    if format.get(i) == Some(&b'*') {
+                        let width = self.next_signed();
+                        spec.left |= width < 0;
+                        spec.width = width.unsigned_abs() as usize;
+                        i += 1;
+                    } else {
+                        let digits = count_digits(&format[i..], 10, usize::MAX);
+                        spec.width = parse_number(&format[i..i + digits]);
+                        i += digits;
+                    }
+                    if format.get(i) == Some(&b'.') {
+                        allowed.retain(|c| c != 'c');
+                        i += 1;
+                        if format.get(i) == Some(&b'*') {
+                            // A negative precision counts as none at all.
+                            spec.precision = usize::try_from(self.next_signed()).ok();
+                            i += 1;
+                        } else {
+                            let digits = count_digits(&format[i..], 10, usize::MAX);
+                            spec.precision = Some(parse_number(&format[i..i + digits]));
+                            i += digits;
+                        }
+                    }
+                    // C length modifiers change nothing, every value is 64-bit here.
+                    while format.get(i).is_some_and(|b| b"hlLjzt".contains(b)) {
+                        i += 1;
+                    }
+
+                    let conversion = format.get(i).copied();
+                    i += 1;
+                    match conversion {
+                        Some(conversion) if allowed.contains(conversion as char) => {
+                            self.convert(conversion, &spec)
+                        }
+                        _ => {
+                            return Err(format!(
+                                "{}: invalid conversion specification",
+                                String::from_utf8_lossy(&format[start..i.min(format.len())])
+                            ))
+                        }
+                    }
+                }
+                byte => {
+                    self.out.push(byte);
+                    i += 1;
                 }
-                's' => result.push_str(&arguments[arg_index]),
-                '%' => result.push('%'),
-                _ => return Err(format!("Unsupported format specifier: %{}", specifier)),
             }
-            arg_index += 1;
+        }
+        Ok(true)
+    }
+
+    fn convert(&mut self, conversion: u8, spec: &Spec) {
+        match conversion {
+            b'd' | b'i' => {
+                let value = self.next_signed();
+                let digits = value.unsigned_abs().to_string();
+                let sign = if value < 0 {
+                    "-"
+                } else if spec.plus {
+                    "+"
+                } else if spec.space {
+                    " "
+                } else {
+                    ""
+                };
+                self.pad_integer(sign, digits, spec, false);
+            }
+            b'o' | b'u' | b'x' | b'X' => {
+                let value = self.next_unsigned();
+                let digits = match conversion {
+                    b'o' => format!("{:o}", value),
+                    b'u' => value.to_string(),
+                    b'x' => format!("{:x}", value),
+                    _ => format!("{:X}", value),
+                };
+                let prefix = match conversion {
+                    b'x' if spec.alternate && value != 0 => "0x",
+                    b'X' if spec.alternate && value != 0 => "0X",
+                    _ => "",
+                };
+                self.pad_integer(prefix, digits, spec, conversion == b'o');
+            }
+            b'f' | b'F' | b'e' | b'E' | b'g' | b'G' => {
+                let value = self.next_float();
+                let text = format_float(value, conversion, spec);
+                let (sign, body) = match text.strip_prefix('-') {
+                    Some(body) => ("-", body.to_string()),
+                    None if spec.plus => ("+", text),
+                    None if spec.space => (" ", text),
+                    None => ("", text),
+                };
+                let zero = spec.zero && value.is_finite();
+                self.pad(sign, body.as_bytes(), spec.width, spec.left, zero);
+            }
+            b'c' => {
+                let argument = self.next_argument();
+                let byte = argument.bytes().next().unwrap_or(0);
+                self.pad("", &[byte], spec.width, spec.left, false);
+            }
+            _ => {
+                let argument = self.next_argument();
+                let mut bytes = argument.as_bytes();
+                if let Some(precision) = spec.precision {
+                    bytes = &bytes[..precision.min(bytes.len())];
+                }
+                self.pad("", bytes, spec.width, spec.left, false);
+            }
+        }
+    }
+
+    // Applies the precision as a minimum digit count, which disables the `0` flag.
+    // With `#`, octal output always starts with a zero.
+    fn pad_integer(&mut self, prefix: &str, mut digits: String, spec: &Spec, octal: bool) {
+        if let Some(precision) = spec.precision {
+            if precision == 0 && digits == "0" {
+                digits.clear();
+            }
+            if digits.len() < precision {
+                digits.insert_str(0, &"0".repeat(precision - digits.len()));
+            }
+        }
+        if octal && spec.alternate && !digits.starts_with('0') {
+            digits.insert(0, '0');
+        }
+        let zero = spec.zero && spec.precision.is_none();
+        self.pad(prefix, digits.as_bytes(), spec.width, spec.left, zero);
+    }
+
+    // Zero padding goes between the sign or prefix and the digits.
+    fn pad(&mut self, prefix: &str, body: &[u8], width: usize, left: bool, zero: bool) {
+        let fill = width.saturating_sub(prefix.len() + body.len());
+        if left {
+            self.out.extend_from_slice(prefix.as_bytes());
+            self.out.extend_from_slice(body);
+            self.out.resize(self.out.len() + fill, b' ');
+        } else if zero {
+            self.out.extend_from_slice(prefix.as_bytes());
+            self.out.resize(self.out.len() + fill, b'0');
+            self.out.extend_from_slice(body);
         } else {
-            result.push(c);
+            self.out.resize(self.out.len() + fill, b' ');
+            self.out.extend_from_slice(prefix.as_bytes());
+            self.out.extend_from_slice(body);
+        }
+    }
+
+    // Missing arguments read as empty strings or zero.
+    fn next_argument(&mut self) -> String {
+        let argument = self.arguments.get(self.next).cloned().unwrap_or_default();
+        self.next += 1;
+        argument
+    }
+
+    // Out of range values are clamped, as strtoimax does.
+    fn next_signed(&mut self) -> i64 {
+        let argument = self.next_argument();
+        let (negative, magnitude, rest) = parse_integer(&argument);
+        self.check_converted(&argument, rest);
+        let limit = if negative {
+            i64::MIN.unsigned_abs()
+        } else {
+            i64::MAX as u64
+        };
+        match magnitude {
+            Some(magnitude) if magnitude <= limit && negative => (magnitude as i64).wrapping_neg(),
+            Some(magnitude) if magnitude <= limit => magnitude as i64,
+            _ => {
+                self.out_of_range(&argument);
+                if negative {
+                    i64::MIN
+                } else {
+                    i64::MAX
+                }
+            }
+        }
+    }
+
+    // Negative values wrap around like C's unsigned conversions.
+    fn next_unsigned(&mut self) -> u64 {
+        let argument = self.next_argument();
+        let (negative, magnitude, rest) = parse_integer(&argument);
+        self.check_converted(&argument, rest);
+        match magnitude {
+            Some(magnitude) if negative => magnitude.wrapping_neg(),
+            Some(magnitude) => magnitude,
+            None => {
+                self.out_of_range(&argument);
+                u64::MAX
+            }
+        }
+    }
+
+    fn next_float(&mut self) -> f64 {
+        let argument = self.next_argument();
+        if let Some(value) = char_constant(&argument) {
+            return value as f64;
+        }
+        let trimmed = argument.trim_start();
+        // Longest prefix that parses, so `1.5x` still yields 1.5 with an error.
+        let end = (0..=trimmed.len())
+            .rev()
+            .filter(|&end| trimmed.is_char_boundary(end))
+            .find(|&end| trimmed[..end].parse::<f64>().is_ok())
+            .unwrap_or(0);
+        self.check_converted(&argument, &trimmed[end..]);
+        trimmed[..end].parse().unwrap_or(0.0)
+    }
+
+    fn out_of_range(&mut self, argument: &str) {
+        if self.error.is_none() {
+            self.error = Some(format!("'{}': Numerical result out of range", argument));
+        }
+    }
+
+    fn check_converted(&mut self, argument: &str, rest: &str) {
+        if self.error.is_some() || argument.is_empty() {
+            return;
+        }
+        if rest.len() == argument.trim_start().len() {
+            self.error = Some(format!("'{}': expected a numeric value", argument));
+        } else if !rest.is_empty() {
+            self.error = Some(format!("'{}': value not completely converted", argument));
         }
     }
+}
 
-    if arg_index < arguments.len() {
-        return Err(format!(
-            "Too many arguments: expected {}, got {}",
-            arg_index,
-            arguments.len()
-        ));
+// Finds where the escape sequence starting at `start` ends.
+fn escape_end(format: &[u8], start: usize) -> usize {
+    let rest = &format[start + 1..];
+    let length = match rest.first() {
+        Some(b'x') => 1 + count_digits(&rest[1..], 16, 2),
+        Some(b'0'..=b'7') => count_digits(rest, 8, 3),
+        Some(_) => 1,
+        None => 0,
+    };
+    start + 1 + length
+}
+
+fn parse_number(digits: &[u8]) -> usize {
+    std::str::from_utf8(digits)
+        .ok()
+        .and_then(|digits| digits.parse().ok())
+        .unwrap_or(0)
+}
+
+// A leading quote gives the character code: printf '%d' "'A" prints 65.
+fn char_constant(argument: &str) -> Option<u32> {
+    let rest = argument
+        .strip_prefix('\'')
+        .or_else(|| argument.strip_prefix('"'))?;
+    rest.chars().next().map(|c| c as u32)
+}
+
+// Parses C integer syntax (decimal, 0x hex, 0 octal) into a sign and a
+// magnitude, None on overflow, and returns what was left unparsed.
+fn parse_integer(argument: &str) -> (bool, Option<u64>, &str) {
+    if let Some(value) = char_constant(argument) {
+        return (false, Some(u64::from(value)), "");
     }
+    let text = argument.trim_start();
+    let (negative, unsigned) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let (radix, digits) = if let Some(hex) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+    {
+        (16, hex)
+    } else if unsigned.starts_with('0') {
+        (8, unsigned)
+    } else {
+        (10, unsigned)
+    };
+    let length = digits
+        .bytes()
+        .take_while(|b| (*b as char).is_digit(radix))
+        .count();
+    if length == 0 {
+        return (false, Some(0), text);
+    }
+    let magnitude = u64::from_str_radix(&digits[..length], radix).ok();
+    (negative, magnitude, &digits[length..])
+}
 
-    Ok(result)
+fn format_float(value: f64, conversion: u8, spec: &Spec) -> String {
+    let upper = conversion.is_ascii_uppercase();
+    let text = if !value.is_finite() {
+        let text = if value.is_nan() {
+            "nan"
+        } else if value < 0.0 {
+            "-inf"
+        } else {
+            "inf"
+        };
+        text.to_string()
+    } else {
+        let precision = spec.precision.unwrap_or(6);
+        match conversion.to_ascii_lowercase() {
+            b'f' => {
+                let mut text = format!("{:.*}", precision, value);
+                if spec.alternate && precision == 0 {
+                    text.push('.');
+                }
+                text
+            }
+            b'e' => {
+                let mut text = format_exponent(value, precision);
+                if spec.alternate && precision == 0 {
+                    let e = text.find('e').unwrap_or(text.len());
+                    text.insert(e, '.');
+                }
+                text
+            }
+            _ => format_general(value, precision.max(1), spec.alternate),
+        }
+    };
+    if upper {
+        text.to_uppercase()
+    } else {
+        text
+    }
+}
+
+// Rust writes `1.5e2`, C wants a signed exponent of at least two digits: `1.5e+02`.
+fn format_exponent(value: f64, precision: usize) -> String {
+    let text = format!("{:.*e}", precision, value);
+    let (mantissa, exponent) = text.split_once('e').unwrap_or((&text, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    format!(
+        "{}e{}{:02}",
+        mantissa,
+        if exponent < 0 { '-' } else { '+' },
+        exponent.unsigned_abs()
+    )
+}
+
+// %g picks %e or %f from the exponent, then drops trailing zeros unless `#`.
+fn format_general(value: f64, precision: usize, alternate: bool) -> String {
+    let rounded = format!("{:.*e}", precision - 1, value);
+    let exponent: i64 = rounded
+        .split_once('e')
+        .and_then(|(_, exponent)| exponent.parse().ok())
+        .unwrap_or(0);
+    let mut text = if exponent < -4 || exponent >= precision as i64 {
+        format_exponent(value, precision - 1)
+    } else {
+        format!("{:.*}", (precision as i64 - 1 - exponent) as usize, value)
+    };
+    if !alternate {
+        let e = text.find('e').unwrap_or(text.len());
+        let (mantissa, exponent) = text.split_at(e);
+        if mantissa.contains('.') {
+            let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+            text = format!("{}{}", mantissa, exponent);
+        }
+    }
+    text
 }
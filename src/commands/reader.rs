@@ -0,0 +1,121 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom},
+    mem,
+    sync::Mutex,
+};
+
+/// Name used for standard input, both on the command line and in messages.
+pub const STDIN: &str = "-";
+
+// Console input a command read ahead of the shell, e.g. keys typed during `tail -f`.
+static UNREAD: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+/// Opens `path` for buffered reading, `-` reads from standard input.
+pub fn open(path: &str) -> Result<Box<dyn BufRead>, String> {
+    if path == STDIN {
+        // Share the process wide buffer with the shell, a private one would read
+        // ahead and swallow the command lines that follow.
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file = File::open(path).map_err(|e| format!("unable to open file '{}': {}", path, e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Parses a decimal or `0x` prefixed hexadecimal byte offset.
+pub fn parse_offset(value: &str) -> Result<u64, String> {
+    let parsed = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|_| format!("invalid offset '{}'", value))
+}
+
+/// Opens `name` positioned `skip` bytes in. Files are seeked, standard input is read and discarded.
+pub fn open_at(name: &str, skip: u64) -> Result<Box<dyn BufRead>, String> {
+    if name != STDIN {
+        let mut file =
+            File::open(name).map_err(|e| format!("unable to open file '{}': {}", name, e))?;
+        file.seek(SeekFrom::Start(skip))
+            .map_err(|e| format!("seek on '{}' failed: {}", name, e))?;
+        return Ok(Box::new(BufReader::new(file)));
+    }
+
+    let mut input = open(name)?;
+    let skipped = io::copy(&mut (&mut input).take(skip), &mut io::sink())
+        .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+    if skipped < skip {
+        return Err(format!("cannot skip past end of input '{}'", name));
+    }
+    Ok(input)
+}
+
+/// Returns the operands to read from, standard input when none were given.
+pub fn inputs<'a>(files: &[&'a str]) -> Vec<&'a str> {
+    if files.is_empty() {
+        vec![STDIN]
+    } else {
+        files.to_vec()
+    }
+}
+
+/// Reads one line including its trailing `\n`, returns `None` at end of input.
+/// Lines are kept as bytes so invalid UTF-8 does not abort the caller.
+pub fn read_line(reader: &mut dyn BufRead, name: &str) -> Result<Option<Vec<u8>>, String> {
+    let mut line = Vec::new();
+    match reader.read_until(b'\n', &mut line) {
+        Ok(0) => Ok(None),
+        Ok(_) => Ok(Some(line)),
+        Err(e) => Err(format!("reading '{}' failed: {}", name, e)),
+    }
+}
+
+/// Fills `buffer` as far as the input allows, returns fewer bytes only at end of input.
+pub fn read_full(reader: &mut dyn Read, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// Hands console input back so the next command line starts with it.
+pub fn unread(bytes: &[u8]) {
+    UNREAD.lock().unwrap().extend_from_slice(bytes);
+}
+
+/// Reads one command line from the console, beginning with any input handed back
+/// by `unread`. Returns the number of bytes read, 0 at end of input.
+pub fn read_console_line(line: &mut String) -> io::Result<usize> {
+    let mut bytes = mem::take(&mut *UNREAD.lock().unwrap());
+    match bytes.iter().position(|&byte| byte == b'\n') {
+        Some(end) => *UNREAD.lock().unwrap() = bytes.split_off(end + 1),
+        None => {
+            io::stdin().lock().read_until(b'\n', &mut bytes)?;
+        }
+    }
+    line.push_str(&String::from_utf8_lossy(&bytes));
+    Ok(bytes.len())
+}
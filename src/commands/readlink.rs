@@ -0,0 +1,46 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::cd::resolve_path;
+use std::{fs, path::Path};
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut canonicalize = false;
+    let mut files = Vec::new();
+
+    for arg in args {
+        match *arg {
+            "-f" | "--canonicalize" => canonicalize = true,
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+
+    if files.is_empty() {
+        return Err("Usage: readlink [-f] <path>...".to_string());
+    }
+
+    for file in files {
+        let path = Path::new(file);
+        if canonicalize {
+            println!("{}", resolve_path(path)?.display());
+        } else {
+            let target =
+                fs::read_link(path).map_err(|e| format!("cannot read link '{}': {}", file, e))?;
+            println!("{}", target.display());
+        }
+    }
+
+    Ok(())
+}
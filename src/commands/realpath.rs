@@ -0,0 +1,32 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::cd::resolve_path;
+use std::path::Path;
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    if args.is_empty() {
+        return Err("Usage: realpath <path>...".to_string());
+    }
+
+    for arg in args {
+        let resolved = resolve_path(Path::new(arg))?;
+        if !resolved.exists() {
+            return Err(format!("'{}': No such file or directory", arg));
+        }
+        println!("{}", resolved.display());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,682 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A small POSIX-style regular expression engine.
+//!
+//! Patterns are compiled to a Thompson NFA and run with a Pike VM, so matching
+//! is linear in the input length and never backtracks. Only `alloc` types are
+//! used, which keeps the engine usable without the full standard library.
+
+// POSIX RE_DUP_MAX, the largest count a single `{n,m}` accepts.
+const MAX_REPEAT: u32 = 255;
+// Nested intervals multiply, so the compiled program is capped as a whole.
+const MAX_PROGRAM: usize = 10_000;
+// Parsing and compiling recurse once per group or stacked repeat, keep them well
+// inside the shell's 64 KiB stack.
+const MAX_NESTING: usize = 16;
+const TOO_BIG: &str = "Regular expression too big";
+
+#[derive(Clone, Copy)]
+enum Named {
+    Alnum,
+    Alpha,
+    Blank,
+    Digit,
+    Lower,
+    Punct,
+    Space,
+    Upper,
+    Word,
+    XDigit,
+}
+
+impl Named {
+    fn from_posix(name: &str) -> Option<Named> {
+        Some(match name {
+            "alnum" => Named::Alnum,
+            "alpha" => Named::Alpha,
+            "blank" => Named::Blank,
+            "digit" => Named::Digit,
+            "lower" => Named::Lower,
+            "punct" => Named::Punct,
+            "space" => Named::Space,
+            "upper" => Named::Upper,
+            "xdigit" => Named::XDigit,
+            _ => return None,
+        })
+    }
+
+    fn matches(self, c: char) -> bool {
+        match self {
+            Named::Alnum => c.is_alphanumeric(),
+            Named::Alpha => c.is_alphabetic(),
+            Named::Blank => c == ' ' || c == '\t',
+            Named::Digit => c.is_ascii_digit(),
+            Named::Lower => c.is_lowercase(),
+            Named::Punct => c.is_ascii_punctuation(),
+            Named::Space => c.is_whitespace(),
+            Named::Upper => c.is_uppercase(),
+            Named::Word => c.is_alphanumeric() || c == '_',
+            Named::XDigit => c.is_ascii_hexdigit(),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum ClassItem {
+    Range(char, char),
+    Named(Named, bool),
+}
+
+#[derive(Clone)]
+struct Class {
+    negated: bool,
+    items: Vec<ClassItem>,
+}
+
+impl Class {
+    fn named(named: Named, negated: bool) -> Class {
+        Class {
+            negated: false,
+            items: vec![ClassItem::Named(named, negated)],
+        }
+    }
+
+    // Case folding applies to the listed items, the bracket's `^` is applied after,
+    // so `[^a-z]` rejects `A` under `-i`.
+    fn matches(&self, c: char, ignore_case: bool) -> bool {
+        let found = if ignore_case {
+            [c, to_lower(c), to_upper(c)]
+                .into_iter()
+                .any(|c| self.contains(c))
+        } else {
+            self.contains(c)
+        };
+        found != self.negated
+    }
+
+    fn contains(&self, c: char) -> bool {
+        self.items.iter().any(|item| match *item {
+            ClassItem::Range(lo, hi) => lo <= c && c <= hi,
+            ClassItem::Named(named, negated) => named.matches(c) != negated,
+        })
+    }
+}
+
+#[derive(Clone)]
+enum Node {
+    Empty,
+    Literal(char),
+    Any,
+    Class(Class),
+    LineStart,
+    LineEnd,
+    Concat(Vec<Node>),
+    Alternate(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+enum Token {
+    Literal(char),
+    Any,
+    Class(Class),
+    LineStart,
+    LineEnd,
+    // The operator as written, kept for when there is nothing to repeat.
+    Repeat(u32, Option<u32>, String),
+    Pipe,
+    Open,
+    Close,
+}
+
+enum Inst {
+    Char(char),
+    Any,
+    Class(Class),
+    LineStart,
+    LineEnd,
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+pub struct Regex {
+    program: Vec<Inst>,
+    ignore_case: bool,
+}
+
+impl Regex {
+    /// Compiles `pattern` as a POSIX extended (`extended`) or GNU basic regular
+    /// expression. In basic syntax `\(`, `\)`, `\{`, `\}`, `\|`, `\+` and `\?`
+    /// are the operators and their unescaped forms are literals.
+    pub fn new(pattern: &str, extended: bool, ignore_case: bool) -> Result<Regex, String> {
+        let tokens = tokenize(pattern, extended)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            depth: 0,
+        };
+        let node = parser.parse_alternate()?;
+        if parser.pos != parser.tokens.len() {
+            return Err("Unmatched ) or \\)".to_string());
+        }
+
+        let mut program = Vec::new();
+        compile(&node, &mut program)?;
+        program.push(Inst::Match);
+        Ok(Regex {
+            program,
+            ignore_case,
+        })
+    }
+
+    /// Returns whether the pattern matches anywhere in `text`.
+    pub fn is_match(&self, text: &str) -> bool {
+        let chars: Vec<char> = text.chars().collect();
+        let mut current = Vec::new();
+        let mut next = Vec::new();
+        // Stamped with the position a pc was last added at, avoids duplicate threads.
+        let mut visited = vec![usize::MAX; self.program.len()];
+
+        for pos in 0..=chars.len() {
+            // Unanchored search: every position starts a new thread.
+            if self.add_thread(&mut current, &mut visited, 0, pos, &chars) {
+                return true;
+            }
+            if pos == chars.len() {
+                break;
+            }
+
+            let c = chars[pos];
+            for &pc in &current {
+                let matched = match &self.program[pc] {
+                    Inst::Char(expected) => self.char_eq(*expected, c),
+                    Inst::Any => true,
+                    Inst::Class(class) => class.matches(c, self.ignore_case),
+                    _ => false,
+                };
+                if matched && self.add_thread(&mut next, &mut visited, pc + 1, pos + 1, &chars) {
+                    return true;
+                }
+            }
+
+            current.clear();
+            core::mem::swap(&mut current, &mut next);
+        }
+
+        false
+    }
+
+    // Follows the epsilon closure of `pc`, returns true once `Match` is reachable.
+    fn add_thread(
+        &self,
+        list: &mut Vec<usize>,
+        visited: &mut [usize],
+        pc: usize,
+        pos: usize,
+        chars: &[char],
+    ) -> bool {
+        let mut stack = vec![pc];
+        while let Some(pc) = stack.pop() {
+            if visited[pc] == pos {
+                continue;
+            }
+            visited[pc] = pos;
+            match self.program[pc] {
+                Inst::Match => return true,
+                Inst::Jump(target) => stack.push(target),
+                Inst::Split(first, second) => {
+                    stack.push(second);
+                    stack.push(first);
+                }
+                Inst::LineStart if pos == 0 => stack.push(pc + 1),
+                Inst::LineEnd if pos == chars.len() => stack.push(pc + 1),
+                Inst::LineStart | Inst::LineEnd => {}
+                _ => list.push(pc),
+            }
+        }
+        false
+    }
+
+    fn char_eq(&self, expected: char, c: char) -> bool {
+        expected == c || (self.ignore_case && to_lower(expected) == to_lower(c))
+    }
+}
+
+fn to_lower(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn to_upper(c: char) -> char {
+    c.to_uppercase().next().unwrap_or(c)
+}
+
+fn tokenize(pattern: &str, extended: bool) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        i += 1;
+        let token = match c {
+            '\\' => {
+                let escaped = *chars.get(i).ok_or("Trailing backslash")?;
+                i += 1;
+                match escaped {
+                    'd' => Token::Class(Class::named(Named::Digit, false)),
+                    'D' => Token::Class(Class::named(Named::Digit, true)),
+                    'w' => Token::Class(Class::named(Named::Word, false)),
+                    'W' => Token::Class(Class::named(Named::Word, true)),
+                    's' => Token::Class(Class::named(Named::Space, false)),
+                    'S' => Token::Class(Class::named(Named::Space, true)),
+                    't' => Token::Literal('\t'),
+                    'n' => Token::Literal('\n'),
+                    '(' if !extended => Token::Open,
+                    ')' if !extended => Token::Close,
+                    '|' if !extended => Token::Pipe,
+                    '+' if !extended => Token::Repeat(1, None, "+".to_string()),
+                    '?' if !extended => Token::Repeat(0, Some(1), "?".to_string()),
+                    '{' if !extended => parse_interval(&chars, &mut i, "\\}")?,
+                    other => Token::Literal(other),
+                }
+            }
+            '.' => Token::Any,
+            '[' => Token::Class(parse_bracket(&chars, &mut i)?),
+            '^' => Token::LineStart,
+            '$' => Token::LineEnd,
+            '*' => Token::Repeat(0, None, "*".to_string()),
+            '+' if extended => Token::Repeat(1, None, "+".to_string()),
+            '?' if extended => Token::Repeat(0, Some(1), "?".to_string()),
+            '{' if extended => parse_interval(&chars, &mut i, "}")?,
+            '|' if extended => Token::Pipe,
+            '(' if extended => Token::Open,
+            ')' if extended => Token::Close,
+            other => Token::Literal(other),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+// Parses the body of `{n}`, `{n,}` or `{n,m}` after the opening brace.
+fn parse_interval(chars: &[char], i: &mut usize, close: &str) -> Result<Token, String> {
+    let rest: String = chars[*i..].iter().collect();
+    let end = rest.find(close).ok_or("Unmatched { or \\{")?;
+    let body = &rest[..end];
+    *i += body.chars().count() + close.chars().count();
+
+    let invalid = || format!("Invalid content of {{{}}}", body);
+    let parse = |s: &str| -> Result<u32, String> {
+        s.trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|n| *n <= MAX_REPEAT)
+            .ok_or_else(invalid)
+    };
+    let (min, max) = match body.split_once(',') {
+        Some((min, "")) => (parse(min)?, None),
+        Some((min, max)) => (parse(min)?, Some(parse(max)?)),
+        None => {
+            let n = parse(body)?;
+            (n, Some(n))
+        }
+    };
+    if max.is_some_and(|max| max < min) {
+        return Err(invalid());
+    }
+    Ok(Token::Repeat(min, max, format!("{{{}}}", body)))
+}
+
+// Parses a bracket expression after the opening `[`.
+fn parse_bracket(chars: &[char], i: &mut usize) -> Result<Class, String> {
+    let mut class = Class {
+        negated: false,
+        items: Vec::new(),
+    };
+    if chars.get(*i) == Some(&'^') {
+        class.negated = true;
+        *i += 1;
+    }
+
+    let mut first = true;
+    loop {
+        let c = *chars.get(*i).ok_or("Unmatched [ or [^")?;
+        *i += 1;
+        if c == ']' && !first {
+            break;
+        }
+        first = false;
+
+        if c == '[' && chars.get(*i) == Some(&':') {
+            let rest: String = chars[*i + 1..].iter().collect();
+            let end = rest.find(":]").ok_or("Unmatched [ or [^")?;
+            let name = &rest[..end];
+            let named = Named::from_posix(name)
+                .ok_or_else(|| format!("Invalid character class name '{}'", name))?;
+            class.items.push(ClassItem::Named(named, false));
+            *i += 1 + name.chars().count() + 2;
+            continue;
+        }
+
+        if chars.get(*i) == Some(&'-') && chars.get(*i + 1).is_some_and(|&n| n != ']') {
+            let hi = chars[*i + 1];
+            if hi < c {
+                return Err("Invalid range end".to_string());
+            }
+            class.items.push(ClassItem::Range(c, hi));
+            *i += 2;
+        } else {
+            class.items.push(ClassItem::Range(c, c));
+        }
+    }
+
+    Ok(class)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    // Groups currently open.
+    depth: usize,
+}
+
+impl Parser {
+    fn parse_alternate(&mut self) -> Result<Node, String> {
+        let mut branches = vec![self.parse_concat()?];
+        while let Some(Token::Pipe) = self.tokens.get(self.pos) {
+            self.pos += 1;
+            branches.push(self.parse_concat()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap_or(Node::Empty)
+        } else {
+            Node::Alternate(branches)
+        })
+    }
+
+    fn parse_concat(&mut self) -> Result<Node, String> {
+        let mut nodes: Vec<Node> = Vec::new();
+        while let Some(token) = self.tokens.get(self.pos) {
+            let node = match token {
+                Token::Pipe | Token::Close => break,
+                Token::Repeat(min, max, text) => match nodes.pop() {
+                    Some(node) => {
+                        // A repeat of a repeat compiles one level deeper, like a group.
+                        if self.depth + stacked_repeats(&node) > MAX_NESTING {
+                            return Err(TOO_BIG.to_string());
+                        }
+                        Node::Repeat {
+                            node: Box::new(node),
+                            min: *min,
+                            max: *max,
+                        }
+                    }
+                    // A leading operator has nothing to repeat and matches itself.
+                    None => Node::Concat(text.chars().map(Node::Literal).collect()),
+                },
+                Token::Literal(c) => Node::Literal(*c),
+                Token::Any => Node::Any,
+                Token::Class(class) => Node::Class(class.clone()),
+                Token::LineStart => Node::LineStart,
+                Token::LineEnd => Node::LineEnd,
+                Token::Open => {
+                    if self.depth == MAX_NESTING {
+                        return Err("Parentheses nested too deeply".to_string());
+                    }
+                    self.pos += 1;
+                    self.depth += 1;
+                    let inner = self.parse_alternate()?;
+                    self.depth -= 1;
+                    if !matches!(self.tokens.get(self.pos), Some(Token::Close)) {
+                        return Err("Unmatched ( or \\(".to_string());
+                    }
+                    inner
+                }
+            };
+            self.pos += 1;
+            nodes.push(node);
+        }
+        // A lone node is not wrapped, groups would otherwise add a level each.
+        Ok(if nodes.len() == 1 {
+            nodes.pop().unwrap_or(Node::Empty)
+        } else {
+            Node::Concat(nodes)
+        })
+    }
+}
+
+fn stacked_repeats(mut node: &Node) -> usize {
+    let mut count = 0;
+    while let Node::Repeat { node: inner, .. } = node {
+        count += 1;
+        node = inner;
+    }
+    count
+}
+
+fn compile(node: &Node, program: &mut Vec<Inst>) -> Result<(), String> {
+    // Checked on entry, so a runaway repeat stops at its next copy.
+    if program.len() > MAX_PROGRAM {
+        return Err(TOO_BIG.to_string());
+    }
+    // The recursive arms live in their own functions, which keeps this frame small.
+    match node {
+        Node::Empty => {}
+        Node::Literal(c) => program.push(Inst::Char(*c)),
+        Node::Any => program.push(Inst::Any),
+        Node::Class(class) => program.push(Inst::Class(class.clone())),
+        Node::LineStart => program.push(Inst::LineStart),
+        Node::LineEnd => program.push(Inst::LineEnd),
+        Node::Concat(nodes) => return compile_concat(nodes, program),
+        Node::Alternate(branches) => return compile_alternate(branches, program),
+        Node::Repeat { node, min, max } => return compile_repeat(node, *min, *max, program),
+    }
+    Ok(())
+}
+
+fn compile_concat(nodes: &[Node], program: &mut Vec<Inst>) -> Result<(), String> {
+    for node in nodes {
+        compile(node, program)?;
+    }
+    Ok(())
+}
+
+fn compile_alternate(branches: &[Node], program: &mut Vec<Inst>) -> Result<(), String> {
+    let mut jumps = Vec::new();
+    for (i, branch) in branches.iter().enumerate() {
+        if i + 1 < branches.len() {
+            let split = program.len();
+            program.push(Inst::Split(split + 1, 0));
+            compile(branch, program)?;
+            jumps.push(program.len());
+            program.push(Inst::Jump(0));
+            let next = program.len();
+            program[split] = Inst::Split(split + 1, next);
+        } else {
+            compile(branch, program)?;
+        }
+    }
+    let end = program.len();
+    for jump in jumps {
+        program[jump] = Inst::Jump(end);
+    }
+    Ok(())
+}
+
+fn compile_repeat(
+    node: &Node,
+    min: u32,
+    max: Option<u32>,
+    program: &mut Vec<Inst>,
+) -> Result<(), String> {
+    for _ in 0..min {
+        compile(node, program)?;
+    }
+    match max {
+        // x* : L: split(body, end); body; jump L
+        None => {
+            let split = program.len();
+            program.push(Inst::Split(split + 1, 0));
+            compile(node, program)?;
+            program.push(Inst::Jump(split));
+            let end = program.len();
+            program[split] = Inst::Split(split + 1, end);
+        }
+        // Each optional copy may be skipped straight to the end.
+        Some(max) => {
+            let mut splits = Vec::new();
+            for _ in min..max {
+                splits.push(program.len());
+                program.push(Inst::Split(program.len() + 1, 0));
+                compile(node, program)?;
+            }
+            let end = program.len();
+            for split in splits {
+                program[split] = Inst::Split(split + 1, end);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, text: &str) -> bool {
+        Regex::new(pattern, true, false).unwrap().is_match(text)
+    }
+
+    #[test]
+    fn anchors() {
+        assert!(matches("^abc", "abcdef"));
+        assert!(!matches("^abc", "xabc"));
+        assert!(matches("def$", "abcdef"));
+        assert!(!matches("def$", "defx"));
+        assert!(matches("^$", ""));
+        assert!(!matches("^$", "a"));
+    }
+
+    #[test]
+    fn bracket_classes() {
+        assert!(matches("[a-c]x", "bx"));
+        assert!(!matches("[a-c]x", "dx"));
+        assert!(matches("[^0-9]", "12a"));
+        assert!(!matches("[^0-9]", "123"));
+        assert!(matches("[]a]", "]"));
+        assert!(matches("[a-]", "-"));
+        assert!(matches("^[[:digit:][:upper:]]+$", "A1B2"));
+        assert!(!matches("^[[:digit:][:upper:]]+$", "A1b2"));
+        assert!(matches("\\d\\s\\w", "1 _"));
+        assert!(!matches("\\D", "123"));
+    }
+
+    #[test]
+    fn intervals() {
+        assert!(matches("^a{3}$", "aaa"));
+        assert!(!matches("^a{3}$", "aa"));
+        assert!(matches("^a{2,}$", "aaaa"));
+        assert!(matches("^a{1,2}b$", "aab"));
+        assert!(!matches("^a{1,2}b$", "aaab"));
+        assert!(Regex::new("a{2,1}", true, false).is_err());
+        assert!(Regex::new("a{256}", true, false).is_err());
+    }
+
+    #[test]
+    fn alternation() {
+        assert!(matches("^(cat|dog)s?$", "dogs"));
+        assert!(!matches("^(cat|dog)s?$", "cow"));
+        assert!(matches("^(a|b|)$", ""));
+    }
+
+    #[test]
+    fn basic_syntax_operators() {
+        let regex = Regex::new("^\\(ab\\)\\{2\\}$", false, false).unwrap();
+        assert!(regex.is_match("abab"));
+        let regex = Regex::new("a+b", false, false).unwrap();
+        assert!(regex.is_match("a+b"));
+        assert!(!regex.is_match("aab"));
+    }
+
+    #[test]
+    fn leading_repeat_is_literal() {
+        assert!(matches("*a", "*a"));
+        assert!(!matches("*a", "a"));
+    }
+
+    #[test]
+    fn unbalanced_groups() {
+        assert!(Regex::new("(a", true, false).is_err());
+        assert!(Regex::new("a)", true, false).is_err());
+    }
+
+    #[test]
+    fn ignore_case() {
+        let regex = Regex::new("^hello [a-z]+$", true, true).unwrap();
+        assert!(regex.is_match("HeLLo World"));
+        let regex = Regex::new("^[^a-z]$", true, true).unwrap();
+        assert!(!regex.is_match("A"));
+        assert!(!regex.is_match("a"));
+        assert!(regex.is_match("1"));
+        let regex = Regex::new("^[^A-Z]$", true, true).unwrap();
+        assert!(!regex.is_match("q"));
+    }
+
+    // The Pike VM never backtracks, so patterns that blow up a backtracking
+    // matcher finish in a single pass.
+    #[test]
+    fn no_exponential_backtracking() {
+        let text = "a".repeat(5_000);
+        assert!(!matches("^(a*)*b$", &text));
+        assert!(!matches("^(a|aa)+b$", &text));
+        assert!(matches("^(a|aa)+$", &text));
+    }
+
+    #[test]
+    fn program_size_limit() {
+        assert!(Regex::new("a{255}", true, false).is_ok());
+        let error = Regex::new("(a{255}){255}", true, false).err();
+        assert_eq!(error.as_deref(), Some(TOO_BIG));
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = |depth: usize| "(a|".repeat(depth) + "b" + &")".repeat(depth);
+        assert!(Regex::new(&nested(9), true, false).is_ok());
+        assert!(Regex::new(&nested(MAX_NESTING), true, false).is_ok());
+        assert!(Regex::new(&nested(MAX_NESTING + 1), true, false).is_err());
+        assert!(Regex::new(&format!("a{}", "*".repeat(MAX_NESTING + 1)), true, false).is_ok());
+        let error = Regex::new(&format!("a{}", "*".repeat(MAX_NESTING + 2)), true, false).err();
+        assert_eq!(error.as_deref(), Some(TOO_BIG));
+    }
+
+    // The deepest pattern the limits allow still compiles on the shell's stack.
+    #[test]
+    fn deepest_pattern_fits_shell_stack() {
+        let pattern = "(x|y(".repeat(MAX_NESTING / 2) + "a*" + &")*)*".repeat(MAX_NESTING / 2);
+        let compiled = std::thread::Builder::new()
+            .stack_size(65536)
+            .spawn(move || Regex::new(&pattern, true, false).is_ok())
+            .unwrap()
+            .join()
+            .unwrap();
+        assert!(compiled);
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+use std::cmp::Ordering;
+
+#[derive(Default)]
+struct Options {
+    numeric: bool,
+    reverse: bool,
+    unique: bool,
+    // 1-based start and optional end field of the sort key.
+    key: Option<(usize, Option<usize>)>,
+    separator: Option<char>,
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut options = Options::default();
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-k" | "--key" => {
+                options.key = Some(parse_key(
+                    iter.next().ok_or("option requires an argument -- 'k'")?,
+                )?)
+            }
+            "-t" | "--field-separator" => {
+                let sep = iter.next().ok_or("option requires an argument -- 't'")?;
+                let mut chars = sep.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => options.separator = Some(c),
+                    _ => return Err(format!("multi-character tab '{}'", sep)),
+                }
+            }
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => {
+                for flag in arg[1..].chars() {
+                    match flag {
+                        'n' => options.numeric = true,
+                        'r' => options.reverse = true,
+                        'u' => options.unique = true,
+                        _ => return Err(format!("invalid option -- '{}'", flag)),
+                    }
+                }
+            }
+            _ => files.push(*arg),
+        }
+    }
+
+    let mut lines = Vec::new();
+    for name in reader::inputs(&files) {
+        let mut input = reader::open(name)?;
+        while let Some(line) = reader::read_line(&mut input, name)? {
+            let line = String::from_utf8_lossy(&line);
+            lines.push(line.trim_end_matches('\n').to_string());
+        }
+    }
+
+    lines.sort_by(|a, b| {
+        let ordering = compare_keys(a, b, &options).then_with(|| a.cmp(b));
+        if options.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    if options.unique {
+        lines.dedup_by(|a, b| compare_keys(a, b, &options) == Ordering::Equal);
+    }
+
+    for line in lines {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+// Accepts `N` or `N,M`, the end field defaults to the end of the line.
+fn parse_key(key: &str) -> Result<(usize, Option<usize>), String> {
+    let invalid = || format!("invalid key '{}'", key);
+    let parse = |field: &str| field.parse::<usize>().ok().filter(|n| *n > 0);
+    match key.split_once(',') {
+        Some((start, end)) => Ok((
+            parse(start).ok_or_else(invalid)?,
+            Some(parse(end).ok_or_else(invalid)?),
+        )),
+        None => Ok((parse(key).ok_or_else(invalid)?, None)),
+    }
+}
+
+fn sort_key<'a>(line: &'a str, options: &Options) -> &'a str {
+    let Some((start, end)) = options.key else {
+        return line;
+    };
+    let fields = field_spans(line, options.separator);
+    let Some(&(begin, _)) = fields.get(start - 1) else {
+        return "";
+    };
+    let finish = end
+        .and_then(|end| fields.get(end - 1))
+        .map(|&(_, finish)| finish)
+        .unwrap_or(line.len());
+    &line[begin..finish.max(begin)]
+}
+
+// Byte ranges of each field, split on `separator` or on runs of blanks.
+fn field_spans(line: &str, separator: Option<char>) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    match separator {
+        Some(sep) => {
+            let mut begin = 0;
+            for (i, c) in line.char_indices() {
+                if c == sep {
+                    spans.push((begin, i));
+                    begin = i + c.len_utf8();
+                }
+            }
+            spans.push((begin, line.len()));
+        }
+        None => {
+            let mut begin = None;
+            for (i, c) in line.char_indices() {
+                if c.is_whitespace() {
+                    if let Some(b) = begin.take() {
+                        spans.push((b, i));
+                    }
+                } else if begin.is_none() {
+                    begin = Some(i);
+                }
+            }
+            if let Some(b) = begin {
+                spans.push((b, line.len()));
+            }
+        }
+    }
+    spans
+}
+
+fn compare_keys(a: &str, b: &str, options: &Options) -> Ordering {
+    let (a, b) = (sort_key(a, options), sort_key(b, options));
+    if options.numeric {
+        leading_number(a)
+            .partial_cmp(&leading_number(b))
+            .unwrap_or(Ordering::Equal)
+    } else {
+        a.cmp(b)
+    }
+}
+
+// Like sort -n, lines without a leading number sort as zero.
+fn leading_number(s: &str) -> f64 {
+    let s = s.trim_start();
+    let end = s
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && c == '-')))
+        .map(|(i, _)| i)
+        .unwrap_or(s.len());
+    s[..end].parse().unwrap_or(0.0)
+}
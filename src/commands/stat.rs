@@ -0,0 +1,194 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ls::{file_type_char, format_permissions};
+use std::{
+    fs::{self, Metadata},
+    os::unix::fs::MetadataExt,
+};
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut format = None;
+    let mut dereference = false;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-c" | "--format" => {
+                format = Some(*iter.next().ok_or("option requires an argument -- 'c'")?);
+            }
+            "-L" | "--dereference" => dereference = true,
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+
+    if files.is_empty() {
+        return Err("Usage: stat [-L] [-c FORMAT] <file>...".to_string());
+    }
+
+    for file in files {
+        let metadata = if dereference {
+            fs::metadata(file)
+        } else {
+            fs::symlink_metadata(file)
+        }
+        .map_err(|e| format!("cannot stat '{}': {}", file, e))?;
+
+        match format {
+            Some(format) => println!("{}", format_custom(format, file, &metadata)),
+            None => print_default(file, &metadata),
+        }
+    }
+
+    Ok(())
+}
+
+fn print_default(name: &str, metadata: &Metadata) {
+    println!("  File: {}", name);
+    println!(
+        "  Size: {:<15} Blocks: {:<10} IO Block: {:<6} {}",
+        metadata.size(),
+        metadata.blocks(),
+        metadata.blksize(),
+        file_type_name(metadata)
+    );
+    println!(
+        "Device: {:<14} Inode: {:<11} Links: {}",
+        metadata.dev(),
+        metadata.ino(),
+        metadata.nlink()
+    );
+    println!(
+        "Access: ({:04o}/{})  Uid: ({:>5})   Gid: ({:>5})",
+        metadata.mode() & 0o7777,
+        symbolic_mode(metadata),
+        metadata.uid(),
+        metadata.gid()
+    );
+    println!(
+        "Access: {}",
+        format_timestamp(metadata.atime(), metadata.atime_nsec())
+    );
+    println!(
+        "Modify: {}",
+        format_timestamp(metadata.mtime(), metadata.mtime_nsec())
+    );
+    println!(
+        "Change: {}",
+        format_timestamp(metadata.ctime(), metadata.ctime_nsec())
+    );
+}
+
+// Supports the subset of GNU stat sequences that MetadataExt can answer.
+fn format_custom(format: &str, name: &str, metadata: &Metadata) -> String {
+    let mut result = String::new();
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push_str(name),
+            Some('s') => result.push_str(&metadata.size().to_string()),
+            Some('b') => result.push_str(&metadata.blocks().to_string()),
+            Some('o') => result.push_str(&metadata.blksize().to_string()),
+            Some('d') => result.push_str(&metadata.dev().to_string()),
+            Some('i') => result.push_str(&metadata.ino().to_string()),
+            Some('h') => result.push_str(&metadata.nlink().to_string()),
+            Some('a') => result.push_str(&format!("{:o}", metadata.mode() & 0o7777)),
+            Some('A') => result.push_str(&symbolic_mode(metadata)),
+            Some('f') => result.push_str(&format!("{:x}", metadata.mode())),
+            Some('F') => result.push_str(file_type_name(metadata)),
+            Some('u') => result.push_str(&metadata.uid().to_string()),
+            Some('g') => result.push_str(&metadata.gid().to_string()),
+            Some('x') => {
+                result.push_str(&format_timestamp(metadata.atime(), metadata.atime_nsec()))
+            }
+            Some('y') => {
+                result.push_str(&format_timestamp(metadata.mtime(), metadata.mtime_nsec()))
+            }
+            Some('z') => {
+                result.push_str(&format_timestamp(metadata.ctime(), metadata.ctime_nsec()))
+            }
+            Some('X') => result.push_str(&metadata.atime().to_string()),
+            Some('Y') => result.push_str(&metadata.mtime().to_string()),
+            Some('Z') => result.push_str(&metadata.ctime().to_string()),
+            Some('%') => result.push('%'),
+            Some(other) => {
+                result.push('%');
+                result.push(other);
+            }
+            None => result.push('%'),
+        }
+    }
+
+    result
+}
+
+fn symbolic_mode(metadata: &Metadata) -> String {
+    format!(
+        "{}{}",
+        file_type_char(&metadata.file_type()),
+        format_permissions(metadata.mode())
+    )
+}
+
+fn file_type_name(metadata: &Metadata) -> &'static str {
+    let file_type = metadata.file_type();
+    if file_type.is_dir() {
+        "directory"
+    } else if file_type.is_symlink() {
+        "symbolic link"
+    } else if metadata.size() == 0 {
+        "regular empty file"
+    } else {
+        "regular file"
+    }
+}
+
+/// Formats seconds since the Unix epoch as `YYYY-MM-DD HH:MM:SS.NNNNNNNNN +0000`.
+/// The kernel has no timezone database, so times are always shown in UTC.
+pub fn format_timestamp(secs: i64, nsecs: i64) -> String {
+    let days = secs.div_euclid(86400);
+    let rem = secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:09} +0000",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60,
+        nsecs
+    )
+}
+
+// Howard Hinnant's days-to-civil algorithm, valid for the whole i64 day range we care about.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
@@ -0,0 +1,259 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write},
+};
+
+const POLL_INTERVAL_MS: i32 = 200;
+const CTRL_C: u8 = 0x03;
+
+/// Where to start printing: the last N units, or everything from unit N (`+N`).
+#[derive(Clone, Copy)]
+enum Count {
+    Last(u64),
+    From(u64),
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut count = Count::Last(10);
+    let mut bytes = false;
+    let mut follow = false;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-n" | "--lines" => {
+                count = parse_count(iter.next().ok_or("option requires an argument -- 'n'")?)?
+            }
+            "-c" | "--bytes" => {
+                count = parse_count(iter.next().ok_or("option requires an argument -- 'c'")?)?;
+                bytes = true;
+            }
+            "-f" | "--follow" => follow = true,
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+
+    let files = reader::inputs(&files);
+    if follow && (files.len() != 1 || files[0] == reader::STDIN) {
+        return Err("tail -f requires exactly one file".to_string());
+    }
+
+    let mut out = io::stdout().lock();
+    let tail = |input: &mut dyn BufRead, name: &str, out: &mut dyn Write| {
+        if bytes {
+            tail_bytes(input, name, count, out)
+        } else {
+            tail_lines(input, name, count, out)
+        }
+    };
+
+    if follow {
+        // Keep the handle so following resumes right where the output stopped,
+        // reopening would skip whatever was appended in between.
+        let name = files[0];
+        let file =
+            File::open(name).map_err(|e| format!("unable to open file '{}': {}", name, e))?;
+        let mut input = BufReader::new(file);
+        tail(&mut input, name, &mut out)?;
+        out.flush().map_err(|e| e.to_string())?;
+        drop(out);
+        // The input was read to its end, so the buffer holds nothing unconsumed.
+        return follow_file(input.into_inner(), name);
+    }
+
+    for (i, name) in files.iter().enumerate() {
+        if files.len() > 1 {
+            let separator = if i == 0 { "" } else { "\n" };
+            writeln!(out, "{}==> {} <==", separator, name).map_err(|e| e.to_string())?;
+        }
+        tail(&mut reader::open(name)?, name, &mut out)?;
+    }
+    out.flush().map_err(|e| e.to_string())
+}
+
+fn parse_count(value: &str) -> Result<Count, String> {
+    let invalid = || format!("invalid number '{}'", value);
+    match value.strip_prefix('+') {
+        Some(from) => from.parse().map(Count::From).map_err(|_| invalid()),
+        None => value.parse().map(Count::Last).map_err(|_| invalid()),
+    }
+}
+
+// Keeps at most `n` lines in memory, so arbitrarily long inputs can be tailed.
+fn tail_lines(
+    input: &mut dyn BufRead,
+    name: &str,
+    count: Count,
+    out: &mut dyn Write,
+) -> Result<(), String> {
+    let mut last = VecDeque::new();
+    let mut line_no = 0;
+    while let Some(line) = reader::read_line(input, name)? {
+        line_no += 1;
+        match count {
+            Count::From(from) if line_no >= from => {
+                out.write_all(&line).map_err(|e| e.to_string())?
+            }
+            Count::From(_) => {}
+            Count::Last(0) => {}
+            Count::Last(n) => {
+                if last.len() as u64 == n {
+                    last.pop_front();
+                }
+                last.push_back(line);
+            }
+        }
+    }
+    for line in last {
+        out.write_all(&line).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn tail_bytes(
+    input: &mut dyn BufRead,
+    name: &str,
+    count: Count,
+    out: &mut dyn Write,
+) -> Result<(), String> {
+    match count {
+        Count::From(from) => {
+            let skip = from.saturating_sub(1);
+            io::copy(&mut input.take(skip), &mut io::sink())
+                .and_then(|_| io::copy(input, out))
+                .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+        }
+        Count::Last(n) => {
+            let mut last = VecDeque::new();
+            let mut buffer = [0u8; 512];
+            loop {
+                let read = input
+                    .read(&mut buffer)
+                    .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+                if read == 0 {
+                    break;
+                }
+                last.extend(&buffer[..read]);
+                let excess = (last.len() as u64).saturating_sub(n) as usize;
+                last.drain(..excess);
+            }
+            let (front, back) = last.as_slices();
+            out.write_all(front)
+                .and_then(|_| out.write_all(back))
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+// Polls the file for appended data until Ctrl-C is typed on the console.
+fn follow_file(mut file: File, name: &str) -> Result<(), String> {
+    let mut position = file
+        .stream_position()
+        .map_err(|e| format!("seek on '{}' failed: {}", name, e))?;
+    let mut buffer = [0u8; 512];
+    let mut out = io::stdout();
+
+    loop {
+        let len = file
+            .metadata()
+            .map_err(|e| format!("unable to stat '{}': {}", name, e))?
+            .len();
+        if len < position {
+            eprintln!("tail: {}: file truncated", name);
+            position = file
+                .seek(SeekFrom::Start(0))
+                .map_err(|e| format!("seek on '{}' failed: {}", name, e))?;
+        }
+
+        loop {
+            let read = file
+                .read(&mut buffer)
+                .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+            if read == 0 {
+                break;
+            }
+            position += read as u64;
+            out.write_all(&buffer[..read]).map_err(|e| e.to_string())?;
+        }
+        out.flush().map_err(|e| e.to_string())?;
+
+        if interrupted(POLL_INTERVAL_MS)? {
+            return Ok(());
+        }
+    }
+}
+
+// Waits up to `timeout_ms` for console input and reports whether following should stop:
+// Ctrl-C was typed, or the console hung up or reached end of input. Anything else typed
+// is handed back to the shell as the start of the next command line.
+fn interrupted(timeout_ms: i32) -> Result<bool, String> {
+    let mut fds = libc::pollfd {
+        fd: libc::STDIN_FILENO,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let ready = unsafe { libc::poll(&mut fds, 1, timeout_ms) };
+    if ready < 0 {
+        let error = io::Error::last_os_error();
+        if error.kind() == io::ErrorKind::Interrupted {
+            return Ok(false);
+        }
+        return Err(format!("waiting for console input failed: {}", error));
+    }
+    if ready == 0 {
+        return Ok(false);
+    }
+    if fds.revents & libc::POLLIN == 0 {
+        // POLLHUP, POLLERR or POLLNVAL, nothing will ever arrive to stop us.
+        return Ok(true);
+    }
+    // Read the descriptor directly, the buffered stdin would keep read ahead input
+    // out of sight of the next poll.
+    let mut buffer = [0u8; 64];
+    let read = unsafe {
+        libc::read(
+            libc::STDIN_FILENO,
+            buffer.as_mut_ptr() as *mut libc::c_void,
+            buffer.len(),
+        )
+    };
+    if read < 0 {
+        let error = io::Error::last_os_error();
+        if error.kind() == io::ErrorKind::Interrupted {
+            return Ok(false);
+        }
+        return Err(format!("reading console input failed: {}", error));
+    }
+    let typed = &buffer[..read as usize];
+    match typed.iter().position(|&byte| byte == CTRL_C) {
+        Some(at) => {
+            reader::unread(&typed[..at]);
+            reader::unread(&typed[at + 1..]);
+            Ok(true)
+        }
+        None => {
+            reader::unread(typed);
+            Ok(typed.is_empty())
+        }
+    }
+}
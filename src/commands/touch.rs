@@ -12,22 +12,149 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{fs::File, path::Path};
+use std::{
+    ffi::CString,
+    fs::{self, File},
+    os::unix::fs::MetadataExt,
+    path::Path,
+};
 
 pub fn command(args: &[&str]) -> Result<(), String> {
     if args.is_empty() {
-        return Err("Usage: touch <file1> <file2> ...".to_string());
+        return Err(
+            "Usage: touch [-a] [-m] [-c] [-d <time>] [-r <ref file>] <file1> <file2> ..."
+                .to_string(),
+        );
     }
 
-    for filename in args {
+    let mut no_create = false;
+    let mut access_only = false;
+    let mut modify_only = false;
+    let mut date = None;
+    let mut reference = None;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-c" | "--no-create" => no_create = true,
+            "-a" => access_only = true,
+            "-m" => modify_only = true,
+            "-d" | "--date" => {
+                let value = iter.next().ok_or("option requires an argument -- 'd'")?;
+                date = Some(parse_time(value)?);
+            }
+            "-r" | "--reference" => {
+                reference = Some(*iter.next().ok_or("option requires an argument -- 'r'")?);
+            }
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+
+    if files.is_empty() {
+        return Err("missing file operand".to_string());
+    }
+
+    let (mut atime, mut mtime) = match (date, reference) {
+        (Some(_), Some(_)) => {
+            return Err("cannot specify times from more than one source".to_string())
+        }
+        (Some(secs), None) => (timespec(secs, 0), timespec(secs, 0)),
+        (None, Some(ref_file)) => {
+            let metadata = fs::metadata(ref_file)
+                .map_err(|e| format!("failed to get attributes of '{}': {}", ref_file, e))?;
+            (
+                timespec(metadata.atime(), metadata.atime_nsec()),
+                timespec(metadata.mtime(), metadata.mtime_nsec()),
+            )
+        }
+        (None, None) => (timespec(0, libc::UTIME_NOW), timespec(0, libc::UTIME_NOW)),
+    };
+
+    // -a and -m each leave the other timestamp untouched; given together they update both.
+    if access_only && !modify_only {
+        mtime.tv_nsec = libc::UTIME_OMIT;
+    } else if modify_only && !access_only {
+        atime.tv_nsec = libc::UTIME_OMIT;
+    }
+
+    for filename in files {
         let path = Path::new(filename);
-        if path.exists() {
-            let _ = File::open(path)
-                .map_err(|e| format!("Unable to open file '{}': {}", filename, e))?;
-        } else {
+        if !path.exists() {
+            if no_create {
+                continue;
+            }
             File::create(path)
                 .map_err(|e| format!("Unable to create file '{}': {}", filename, e))?;
         }
+        set_times(filename, &[atime, mtime])
+            .map_err(|e| format!("setting times of '{}' failed: {}", filename, e))?;
+    }
+    Ok(())
+}
+
+fn set_times(filename: &str, times: &[libc::timespec; 2]) -> Result<(), String> {
+    let path = CString::new(filename).map_err(|e| e.to_string())?;
+    let result = unsafe { libc::utimensat(libc::AT_FDCWD, path.as_ptr(), times.as_ptr(), 0) };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error().to_string());
     }
     Ok(())
 }
+
+fn timespec(secs: i64, nsecs: i64) -> libc::timespec {
+    libc::timespec {
+        tv_sec: secs as _,
+        tv_nsec: nsecs as _,
+    }
+}
+
+/// Parses `@<epoch seconds>`, `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM[:SS]` as UTC seconds
+/// since the epoch. The shell splits arguments on whitespace, so the date and time
+/// must be joined with `T`.
+fn parse_time(value: &str) -> Result<i64, String> {
+    let invalid = || format!("invalid date format '{}'", value);
+
+    if let Some(epoch) = value.strip_prefix('@') {
+        return epoch.parse().map_err(|_| invalid());
+    }
+
+    let (date, time) = value.split_once(['T', '_']).unwrap_or((value, "00:00"));
+    let date: Vec<&str> = date.split('-').collect();
+    let time: Vec<&str> = time.split(':').collect();
+    if date.len() != 3 || !(2..=3).contains(&time.len()) {
+        return Err(invalid());
+    }
+
+    let year: i64 = date[0].parse().map_err(|_| invalid())?;
+    let month: i64 = date[1].parse().map_err(|_| invalid())?;
+    let day: i64 = date[2].parse().map_err(|_| invalid())?;
+    let hour: i64 = time[0].parse().map_err(|_| invalid())?;
+    let minute: i64 = time[1].parse().map_err(|_| invalid())?;
+    let second: i64 = match time.get(2) {
+        Some(s) => s.parse().map_err(|_| invalid())?,
+        None => 0,
+    };
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || !(0..24).contains(&hour)
+        || !(0..60).contains(&minute)
+        || !(0..=60).contains(&second)
+    {
+        return Err(invalid());
+    }
+
+    Ok(days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second)
+}
+
+// Inverse of stat::civil_from_days.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+use std::io::{self, Write};
+
+// Like POSIX tr, input always comes from standard input.
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut delete = false;
+    let mut squeeze = false;
+    let mut sets = Vec::new();
+
+    for arg in args {
+        match *arg {
+            "-d" | "--delete" => delete = true,
+            "-s" | "--squeeze-repeats" => squeeze = true,
+            "-ds" | "-sd" => {
+                delete = true;
+                squeeze = true;
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                return Err(format!("invalid option -- '{}'", arg))
+            }
+            _ => sets.push(expand_set(arg)?),
+        }
+    }
+
+    let valid = match (delete, squeeze) {
+        (true, false) => sets.len() == 1,
+        (true, true) => sets.len() == 2,
+        (false, true) => !sets.is_empty() && sets.len() <= 2,
+        (false, false) => sets.len() == 2,
+    };
+    if !valid {
+        return Err("Usage: tr [-d] [-s] <set1> [<set2>]".to_string());
+    }
+    if !delete && sets.len() == 2 && sets[1].is_empty() && !sets[0].is_empty() {
+        return Err("when not truncating set1, string2 must be non-empty".to_string());
+    }
+
+    let set1 = &sets[0];
+    let translate_to = if delete { None } else { sets.get(1) };
+    let squeeze_set = if squeeze { sets.last() } else { None };
+
+    let mut input = reader::open(reader::STDIN)?;
+    let mut out = io::stdout().lock();
+    let mut last = None;
+    while let Some(line) = reader::read_line(&mut input, reader::STDIN)? {
+        let mut result = String::new();
+        for c in String::from_utf8_lossy(&line).chars() {
+            let position = set1.iter().position(|&s| s == c);
+            let c = match (position, translate_to) {
+                (Some(_), None) if delete => continue,
+                (Some(i), Some(to)) => to[i.min(to.len() - 1)],
+                _ => c,
+            };
+            if squeeze_set.is_some_and(|set| set.contains(&c)) && last == Some(c) {
+                continue;
+            }
+            last = Some(c);
+            result.push(c);
+        }
+        out.write_all(result.as_bytes())
+            .map_err(|e| e.to_string())?;
+    }
+    out.flush().map_err(|e| e.to_string())
+}
+
+// Expands ranges (`a-z`), classes (`[:upper:]`) and escapes (`\n`, `\040`).
+fn expand_set(set: &str) -> Result<Vec<char>, String> {
+    let chars: Vec<char> = set.chars().collect();
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '[' && chars.get(i + 1) == Some(&':') {
+            let rest: String = chars[i + 2..].iter().collect();
+            if let Some(end) = rest.find(":]") {
+                let class: fn(&char) -> bool = match &rest[..end] {
+                    "alnum" => |c| c.is_ascii_alphanumeric(),
+                    "alpha" => |c| c.is_ascii_alphabetic(),
+                    "blank" => |c| *c == ' ' || *c == '\t',
+                    "digit" => |c| c.is_ascii_digit(),
+                    "lower" => |c| c.is_ascii_lowercase(),
+                    "punct" => |c| c.is_ascii_punctuation(),
+                    "space" => |c| c.is_ascii_whitespace(),
+                    "upper" => |c| c.is_ascii_uppercase(),
+                    name => return Err(format!("invalid character class '{}'", name)),
+                };
+                result.extend((0u8..128).map(char::from).filter(class));
+                i += 2 + end + 2;
+                continue;
+            }
+        }
+
+        let (c, len) = unescape(&chars[i..]);
+        i += len;
+        if chars.get(i) == Some(&'-') && i + 1 < chars.len() {
+            let (hi, hi_len) = unescape(&chars[i + 1..]);
+            if hi < c {
+                return Err(format!(
+                    "range-endpoints of '{}-{}' are in reverse collating sequence order",
+                    c, hi
+                ));
+            }
+            result.extend(c..=hi);
+            i += 1 + hi_len;
+        } else {
+            result.push(c);
+        }
+    }
+
+    Ok(result)
+}
+
+// Decodes one possibly escaped character, returns it with the number of chars consumed.
+fn unescape(chars: &[char]) -> (char, usize) {
+    if chars[0] != '\\' || chars.len() == 1 {
+        return (chars[0], 1);
+    }
+    match chars[1] {
+        'n' => ('\n', 2),
+        't' => ('\t', 2),
+        'r' => ('\r', 2),
+        '\\' => ('\\', 2),
+        '0'..='7' => {
+            let digits: String = chars[1..]
+                .iter()
+                .take(3)
+                .take_while(|c| ('0'..='7').contains(c))
+                .collect();
+            let value = u32::from_str_radix(&digits, 8).unwrap_or(0);
+            (char::from_u32(value).unwrap_or('\0'), 1 + digits.len())
+        }
+        other => (other, 2),
+    }
+}
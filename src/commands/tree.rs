@@ -0,0 +1,152 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{ls::format_size, walk::read_sorted};
+use std::{fs, path::Path};
+
+#[derive(Default)]
+struct Options {
+    show_hidden: bool,
+    dirs_only: bool,
+    human: bool,
+    max_depth: Option<usize>,
+}
+
+#[derive(Default)]
+struct Counts {
+    dirs: usize,
+    files: usize,
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut options = Options::default();
+    let mut paths = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-L" => {
+                let level = iter.next().ok_or("option requires an argument -- 'L'")?;
+                options.max_depth = match level.parse() {
+                    Ok(level) if level > 0 => Some(level),
+                    _ => return Err(format!("invalid level '{}', must be greater than 0", level)),
+                };
+            }
+            _ if arg.starts_with('-') && arg.len() > 1 => {
+                for flag in arg[1..].chars() {
+                    match flag {
+                        'a' => options.show_hidden = true,
+                        'd' => options.dirs_only = true,
+                        'h' => options.human = true,
+                        _ => return Err(format!("invalid option -- '{}'", flag)),
+                    }
+                }
+            }
+            _ => paths.push(*arg),
+        }
+    }
+    if paths.is_empty() {
+        paths.push(".");
+    }
+
+    let mut counts = Counts::default();
+    for path in paths {
+        let metadata =
+            fs::symlink_metadata(path).map_err(|e| format!("cannot access '{}': {}", path, e))?;
+        println!("{}", label(Path::new(path), path, &metadata, &options));
+        if metadata.is_dir() {
+            draw(Path::new(path), "", 1, &options, &mut counts)?;
+        }
+    }
+
+    println!();
+    let dirs = plural(counts.dirs, "directory", "directories");
+    if options.dirs_only {
+        println!("{}", dirs);
+    } else {
+        println!("{}, {}", dirs, plural(counts.files, "file", "files"));
+    }
+    Ok(())
+}
+
+// Prints the children of `dir`, `prefix` carries the rails of the levels above.
+fn draw(
+    dir: &Path,
+    prefix: &str,
+    depth: usize,
+    options: &Options,
+    counts: &mut Counts,
+) -> Result<(), String> {
+    let mut children = Vec::new();
+    for path in read_sorted(dir)? {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if !options.show_hidden && name.starts_with('.') {
+            continue;
+        }
+        let metadata = fs::symlink_metadata(&path)
+            .map_err(|e| format!("cannot access '{}': {}", path.display(), e))?;
+        if options.dirs_only && !metadata.is_dir() {
+            continue;
+        }
+        children.push((path, metadata));
+    }
+
+    let count = children.len();
+    for (i, (path, metadata)) in children.into_iter().enumerate() {
+        let last = i + 1 == count;
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let branch = if last { "└── " } else { "├── " };
+        println!(
+            "{}{}{}",
+            prefix,
+            branch,
+            label(&path, &name, &metadata, options)
+        );
+
+        if metadata.is_dir() {
+            counts.dirs += 1;
+            if options.max_depth.is_none_or(|max| depth < max) {
+                let rail = if last { "    " } else { "│   " };
+                draw(
+                    &path,
+                    &format!("{}{}", prefix, rail),
+                    depth + 1,
+                    options,
+                    counts,
+                )?;
+            }
+        } else {
+            counts.files += 1;
+        }
+    }
+    Ok(())
+}
+
+fn label(path: &Path, name: &str, metadata: &fs::Metadata, options: &Options) -> String {
+    let mut label = if options.human {
+        format!("[{:>6}]  {}", format_size(metadata.len()), name)
+    } else {
+        name.to_string()
+    };
+    if metadata.file_type().is_symlink() {
+        if let Ok(target) = fs::read_link(path) {
+            label.push_str(&format!(" -> {}", target.display()));
+        }
+    }
+    label
+}
+
+fn plural(count: usize, one: &str, many: &str) -> String {
+    format!("{} {}", count, if count == 1 { one } else { many })
+}
@@ -12,26 +12,168 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::OpenOptions;
+use std::{
+    fs::{self, OpenOptions},
+    io,
+};
+
+#[derive(Clone, Copy)]
+enum Adjust {
+    Set,
+    Extend,
+    Reduce,
+    AtMost,
+    AtLeast,
+    RoundDown,
+    RoundUp,
+}
 
 pub fn command(args: &[&str]) -> Result<(), String> {
-    if args.len() != 2 {
-        return Err("Usage: truncate <file> <size>".to_string());
+    let mut size = None;
+    let mut reference = None;
+    let mut no_create = false;
+    let mut files = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-s" | "--size" => {
+                size = Some(parse_adjustment(
+                    iter.next().ok_or("option requires an argument -- 's'")?,
+                )?)
+            }
+            "-r" | "--reference" => {
+                reference = Some(*iter.next().ok_or("option requires an argument -- 'r'")?)
+            }
+            "-c" | "--no-create" => no_create = true,
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => files.push(*arg),
+        }
+    }
+
+    // `truncate <file> <size>` is the original syntax, existing scripts still use it.
+    if let ([file, legacy_size], None, None) = (files.as_slice(), size, reference) {
+        if let Ok(legacy_size) = legacy_size.parse() {
+            return resize_existing(file, legacy_size);
+        }
+    }
+    if files.is_empty() || (size.is_none() && reference.is_none()) {
+        return Err(
+            "Usage: truncate [-c] -s [+|-|<|>|/|%]<size>[K|M|G|T][iB|B] | -r <rfile> <file>... / truncate <file> <size>"
+                .to_string(),
+        );
+    }
+    let reference_size = match reference {
+        Some(path) => Some(
+            fs::metadata(path)
+                .map_err(|e| format!("cannot stat '{}': {}", path, e))?
+                .len(),
+        ),
+        None => None,
+    };
+    if let (Some((Adjust::Set, _)), Some(_)) = (size, reference_size) {
+        return Err("you must specify a relative size with a reference file".to_string());
+    }
+
+    for filename in files {
+        let file = match OpenOptions::new()
+            .write(true)
+            .create(!no_create)
+            .truncate(false)
+            .open(filename)
+        {
+            Ok(file) => file,
+            // With -c, files that do not exist are silently left alone.
+            Err(e) if no_create && e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Unable to open file '{}': {}", filename, e)),
+        };
+
+        let current = match reference_size {
+            Some(size) => size,
+            None => file
+                .metadata()
+                .map_err(|e| format!("cannot stat '{}': {}", filename, e))?
+                .len(),
+        };
+        let new_size = match size {
+            Some((adjust, amount)) => adjust_size(current, adjust, amount)?,
+            None => current,
+        };
+        file.set_len(new_size)
+            .map_err(|e| format!("Unable to set size of '{}': {}", filename, e))?;
     }
 
-    let filename = args[0];
-    let size: u64 = args[1]
-        .parse()
-        .map_err(|_| "Invalid size value".to_string())?;
+    Ok(())
+}
 
+// Unlike the -s form, the file must already exist.
+fn resize_existing(filename: &str, size: u64) -> Result<(), String> {
     let file = OpenOptions::new()
-        .read(true)
         .write(true)
         .open(filename)
         .map_err(|e| format!("Unable to open file '{}': {}", filename, e))?;
-
     file.set_len(size)
-        .map_err(|e| format!("Unable to set file size: {}", e))?;
+        .map_err(|e| format!("Unable to set size of '{}': {}", filename, e))
+}
 
-    Ok(())
+fn parse_adjustment(value: &str) -> Result<(Adjust, u64), String> {
+    let adjust = match value.chars().next() {
+        Some('+') => Adjust::Extend,
+        Some('-') => Adjust::Reduce,
+        Some('<') => Adjust::AtMost,
+        Some('>') => Adjust::AtLeast,
+        Some('/') => Adjust::RoundDown,
+        Some('%') => Adjust::RoundUp,
+        _ => return Ok((Adjust::Set, parse_size(value)?)),
+    };
+    Ok((adjust, parse_size(&value[1..])?))
+}
+
+fn adjust_size(current: u64, adjust: Adjust, amount: u64) -> Result<u64, String> {
+    let overflow = || "size is too large".to_string();
+    match adjust {
+        Adjust::Set => Ok(amount),
+        Adjust::Extend => current.checked_add(amount).ok_or_else(overflow),
+        Adjust::Reduce => Ok(current.saturating_sub(amount)),
+        Adjust::AtMost => Ok(current.min(amount)),
+        Adjust::AtLeast => Ok(current.max(amount)),
+        Adjust::RoundDown | Adjust::RoundUp if amount == 0 => Err("division by zero".to_string()),
+        Adjust::RoundDown => Ok(current / amount * amount),
+        Adjust::RoundUp => current
+            .div_ceil(amount)
+            .checked_mul(amount)
+            .ok_or_else(overflow),
+    }
+}
+
+/// Parses a byte count with an optional unit: `K`, `M`, `G`, `T` and their
+/// `KiB` forms are powers of 1024, `KB`, `MB`, ... are powers of 1000.
+pub fn parse_size(value: &str) -> Result<u64, String> {
+    let invalid = || format!("invalid size '{}'", value);
+    let digits = value.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return Err(invalid());
+    }
+    let number: u64 = value[..digits].parse().map_err(|_| invalid())?;
+
+    let suffix = &value[digits..];
+    if suffix.is_empty() {
+        return Ok(number);
+    }
+    let mut chars = suffix.chars();
+    let exponent = match chars.next() {
+        Some('k' | 'K') => 1,
+        Some('m' | 'M') => 2,
+        Some('g' | 'G') => 3,
+        Some('t' | 'T') => 4,
+        _ => return Err(invalid()),
+    };
+    let base: u64 = match chars.as_str() {
+        "" | "iB" => 1024,
+        "B" => 1000,
+        _ => return Err(invalid()),
+    };
+    number
+        .checked_mul(base.pow(exponent))
+        .ok_or_else(|| format!("size '{}' is too large", value))
 }
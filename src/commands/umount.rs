@@ -12,20 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::mount::describe_error;
 use librs::direct;
 use std::ffi::CString;
 
 // std not support, call librs
 pub fn command(args: &[&str]) -> Result<(), String> {
-    if args.len() != 1 {
-        return Err("Usage: unmount <target>".to_string());
+    let mut targets = Vec::new();
+    for arg in args {
+        match *arg {
+            // librs only exposes umount(target), there is no way to pass MNT_FORCE or MNT_DETACH.
+            "-f" | "-l" => return Err(format!("option {} is not supported on this system", arg)),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => targets.push(*arg),
+        }
+    }
+    if targets.len() != 1 {
+        return Err("Usage: umount <target>".to_string());
     }
 
-    let target = CString::new(args[0]).map_err(|e| e.to_string())?;
+    let target = CString::new(targets[0]).map_err(|e| e.to_string())?;
     let result = unsafe { direct::umount(target.as_ptr()) };
 
     if result != 0 {
-        println!("mount failed (error code: {})", result);
+        return Err(format!(
+            "umount '{}' failed: {}",
+            targets[0],
+            describe_error(result)
+        ));
     }
 
     Ok(())
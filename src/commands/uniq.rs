@@ -0,0 +1,75 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut count = false;
+    let mut repeated_only = false;
+    let mut unique_only = false;
+    let mut files = Vec::new();
+
+    for arg in args {
+        match *arg {
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => {
+                for flag in arg[1..].chars() {
+                    match flag {
+                        'c' => count = true,
+                        'd' => repeated_only = true,
+                        'u' => unique_only = true,
+                        _ => return Err(format!("invalid option -- '{}'", flag)),
+                    }
+                }
+            }
+            _ => files.push(*arg),
+        }
+    }
+    if files.len() > 1 {
+        return Err("Usage: uniq [-c] [-d] [-u] [<file>]".to_string());
+    }
+
+    let print = |line: &str, occurrences: usize| {
+        if (repeated_only && occurrences < 2) || (unique_only && occurrences > 1) {
+            return;
+        }
+        if count {
+            println!("{:>7} {}", occurrences, line);
+        } else {
+            println!("{}", line);
+        }
+    };
+
+    let name = reader::inputs(&files)[0];
+    let mut input = reader::open(name)?;
+    let mut previous: Option<(String, usize)> = None;
+    while let Some(line) = reader::read_line(&mut input, name)? {
+        let line = String::from_utf8_lossy(&line)
+            .trim_end_matches('\n')
+            .to_string();
+        match previous.as_mut() {
+            Some((text, occurrences)) if *text == line => *occurrences += 1,
+            _ => {
+                if let Some((text, occurrences)) = previous.replace((line, 1)) {
+                    print(&text, occurrences);
+                }
+            }
+        }
+    }
+    if let Some((text, occurrences)) = previous {
+        print(&text, occurrences);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A file met while walking a tree. `depth` is 0 for the starting path.
+pub struct Entry {
+    pub path: PathBuf,
+    pub metadata: fs::Metadata,
+    pub depth: usize,
+}
+
+/// Recursive directory walker shared by the commands that descend into trees.
+///
+/// Symbolic links are reported but never followed, and directory contents are
+/// visited in name order so output is stable across runs.
+pub struct Walk {
+    max_depth: usize,
+    contents_first: bool,
+}
+
+impl Walk {
+    pub fn new() -> Self {
+        Walk {
+            max_depth: usize::MAX,
+            contents_first: false,
+        }
+    }
+
+    /// Do not descend below `depth` levels under the starting path.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Visit a directory after its contents, as removal and size totals need.
+    pub fn contents_first(mut self) -> Self {
+        self.contents_first = true;
+        self
+    }
+
+    pub fn run(
+        &self,
+        root: &Path,
+        visit: &mut dyn FnMut(&Entry) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let metadata = fs::symlink_metadata(root)
+            .map_err(|e| format!("cannot access '{}': {}", root.display(), e))?;
+        self.visit(
+            Entry {
+                path: root.to_path_buf(),
+                metadata,
+                depth: 0,
+            },
+            visit,
+        )
+    }
+
+    fn visit(
+        &self,
+        entry: Entry,
+        visit: &mut dyn FnMut(&Entry) -> Result<(), String>,
+    ) -> Result<(), String> {
+        if !self.contents_first {
+            visit(&entry)?;
+        }
+        if entry.metadata.is_dir() && entry.depth < self.max_depth {
+            for path in read_sorted(&entry.path)? {
+                let metadata = fs::symlink_metadata(&path)
+                    .map_err(|e| format!("cannot access '{}': {}", path.display(), e))?;
+                self.visit(
+                    Entry {
+                        path,
+                        metadata,
+                        depth: entry.depth + 1,
+                    },
+                    visit,
+                )?;
+            }
+        }
+        if self.contents_first {
+            visit(&entry)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the paths inside `dir`, sorted by name.
+pub fn read_sorted(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Unable to read directory '{}': {}", dir.display(), e))?;
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read dir entry: {}", e))?;
+    paths.sort();
+    Ok(paths)
+}
@@ -0,0 +1,100 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::reader;
+
+#[derive(Default)]
+struct Counts {
+    lines: u64,
+    words: u64,
+    bytes: u64,
+}
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut lines = false;
+    let mut words = false;
+    let mut bytes = false;
+    let mut files = Vec::new();
+
+    for arg in args {
+        match *arg {
+            reader::STDIN => files.push(*arg),
+            _ if arg.starts_with('-') => {
+                for flag in arg[1..].chars() {
+                    match flag {
+                        'l' => lines = true,
+                        'w' => words = true,
+                        'c' => bytes = true,
+                        _ => return Err(format!("invalid option -- '{}'", flag)),
+                    }
+                }
+            }
+            _ => files.push(*arg),
+        }
+    }
+    if !lines && !words && !bytes {
+        lines = true;
+        words = true;
+        bytes = true;
+    }
+
+    let print = |counts: &Counts, name: &str| {
+        let mut columns = Vec::new();
+        if lines {
+            columns.push(format!("{:>7}", counts.lines));
+        }
+        if words {
+            columns.push(format!("{:>7}", counts.words));
+        }
+        if bytes {
+            columns.push(format!("{:>7}", counts.bytes));
+        }
+        if name == reader::STDIN {
+            println!("{}", columns.join(" "));
+        } else {
+            println!("{} {}", columns.join(" "), name);
+        }
+    };
+
+    let files = reader::inputs(&files);
+    let mut total = Counts::default();
+    for name in &files {
+        let counts = count(name)?;
+        print(&counts, name);
+        total.lines += counts.lines;
+        total.words += counts.words;
+        total.bytes += counts.bytes;
+    }
+    if files.len() > 1 {
+        print(&total, "total");
+    }
+
+    Ok(())
+}
+
+fn count(name: &str) -> Result<Counts, String> {
+    let mut input = reader::open(name)?;
+    let mut counts = Counts::default();
+    while let Some(line) = reader::read_line(&mut input, name)? {
+        counts.bytes += line.len() as u64;
+        if line.ends_with(b"\n") {
+            counts.lines += 1;
+        }
+        counts.words += line
+            .split(|b| b.is_ascii_whitespace())
+            .filter(|word| !word.is_empty())
+            .count() as u64;
+    }
+    Ok(counts)
+}
@@ -0,0 +1,197 @@
+// Copyright (c) 2026 vivo Mobile Communication Co., Ltd.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    hexdump::printable,
+    reader::{self, open_at, parse_offset},
+};
+use std::{
+    fs::OpenOptions,
+    io::{self, Read, Seek, SeekFrom, Write},
+};
+
+const BYTES_PER_LINE: usize = 16;
+const PLAIN_BYTES_PER_LINE: usize = 30;
+
+pub fn command(args: &[&str]) -> Result<(), String> {
+    let mut revert = false;
+    let mut plain = false;
+    let mut skip = 0;
+    let mut length = None;
+    let mut operands = Vec::new();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-r" | "-revert" => revert = true,
+            "-p" | "-ps" | "-plain" => plain = true,
+            "-rp" | "-pr" => {
+                revert = true;
+                plain = true;
+            }
+            "-s" | "-seek" => {
+                skip = parse_offset(iter.next().ok_or("option requires an argument -- 's'")?)?
+            }
+            "-l" | "-len" => {
+                length = Some(parse_offset(
+                    iter.next().ok_or("option requires an argument -- 'l'")?,
+                )?)
+            }
+            reader::STDIN => operands.push(*arg),
+            _ if arg.starts_with('-') => return Err(format!("invalid option -- '{}'", arg)),
+            _ => operands.push(*arg),
+        }
+    }
+    if operands.len() > 2 {
+        return Err(
+            "Usage: xxd [-p] [-s <offset>] [-l <length>] [<infile> [<outfile>]] | xxd -r [-p] [<infile> [<outfile>]]"
+                .to_string(),
+        );
+    }
+
+    let input = operands.first().copied().unwrap_or(reader::STDIN);
+    let output = operands.get(1).copied();
+    if revert {
+        reverse(input, output, plain)
+    } else {
+        let mut out: Box<dyn Write> = match output {
+            Some(path) => Box::new(
+                OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(path)
+                    .map_err(|e| format!("unable to create file '{}': {}", path, e))?,
+            ),
+            None => Box::new(io::stdout().lock()),
+        };
+        dump(input, skip, length, plain, &mut out)
+    }
+}
+
+fn dump(
+    name: &str,
+    skip: u64,
+    length: Option<u64>,
+    plain: bool,
+    out: &mut dyn Write,
+) -> Result<(), String> {
+    let input = open_at(name, skip)?;
+    let mut input = input.take(length.unwrap_or(u64::MAX));
+    let width = if plain {
+        PLAIN_BYTES_PER_LINE
+    } else {
+        BYTES_PER_LINE
+    };
+    let mut line = vec![0u8; width];
+    let mut offset = skip;
+
+    loop {
+        let read = reader::read_full(&mut input, &mut line)
+            .map_err(|e| format!("reading '{}' failed: {}", name, e))?;
+        if read == 0 {
+            break;
+        }
+        let bytes = &line[..read];
+
+        let hex: String = if plain {
+            bytes.iter().map(|b| format!("{:02x}", b)).collect()
+        } else {
+            bytes
+                .chunks(2)
+                .map(|pair| pair.iter().map(|b| format!("{:02x}", b)).collect())
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        let result = if plain {
+            writeln!(out, "{}", hex)
+        } else {
+            // 16 bytes take 39 columns: 8 groups of 4 digits and 7 separators.
+            writeln!(out, "{:08x}: {:<39}  {}", offset, hex, printable(bytes))
+        };
+        result.map_err(|e| e.to_string())?;
+        offset += read as u64;
+    }
+
+    out.flush().map_err(|e| e.to_string())
+}
+
+// Turns a dump back into binary. Normal dumps carry their own offsets, which are
+// honoured by seeking the output; plain dumps are just a stream of hex digits.
+fn reverse(name: &str, output: Option<&str>, plain: bool) -> Result<(), String> {
+    let mut input = reader::open(name)?;
+    let mut file = match output {
+        Some(path) => Some(
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path)
+                .map_err(|e| format!("unable to create file '{}': {}", path, e))?,
+        ),
+        None => None,
+    };
+    let mut stdout = io::stdout().lock();
+    let mut line_no = 0;
+
+    while let Some(line) = reader::read_line(&mut input, name)? {
+        line_no += 1;
+        let line = String::from_utf8_lossy(&line);
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        let hex = if plain {
+            line
+        } else {
+            let Some((offset, rest)) = line.split_once(':') else {
+                continue;
+            };
+            let offset = u64::from_str_radix(offset.trim(), 16)
+                .map_err(|_| format!("invalid offset on line {}", line_no))?;
+            if let Some(file) = file.as_mut() {
+                file.seek(SeekFrom::Start(offset))
+                    .map_err(|e| format!("seek failed: {}", e))?;
+            }
+            // The ASCII column starts after two spaces and may itself contain hex digits.
+            rest.split("  ")
+                .find(|s| !s.trim().is_empty())
+                .unwrap_or("")
+        };
+
+        let bytes = decode_hex(hex).map_err(|e| format!("line {}: {}", line_no, e))?;
+        let result = match file.as_mut() {
+            Some(file) => file.write_all(&bytes),
+            None => stdout.write_all(&bytes),
+        };
+        result.map_err(|e| format!("write failed: {}", e))?;
+    }
+
+    stdout.flush().map_err(|e| e.to_string())
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<u8> = hex
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| {
+            c.to_digit(16)
+                .map(|d| d as u8)
+                .ok_or_else(|| format!("invalid hex digit '{}'", c))
+        })
+        .collect::<Result<_, _>>()?;
+    let pairs = digits.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err("odd number of hex digits".to_string());
+    }
+    Ok(pairs.map(|pair| pair[0] << 4 | pair[1]).collect())
+}
@@ -31,6 +31,7 @@ fn main() {
         .stack_size(65536)
         .spawn(move || {
             println!("Hello, shell!");
+            commands::mount::automount();
             shell_loop();
         })
         .unwrap()
@@ -43,7 +44,9 @@ fn shell_loop() {
         print!("> ");
         io::stdout().flush().unwrap();
         let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
+        if commands::reader::read_console_line(&mut input).unwrap() == 0 {
+            break;
+        }
         let input = input.trim();
         if input == "exit" {
             break;
@@ -58,8 +61,11 @@ fn shell_loop() {
         let args = &parts[1..];
         match COMMANDS.get(cmd) {
             Some(info) => {
+                // An empty error is a silent failure, e.g. `cmp -s`.
                 if let Err(e) = (info.handler)(args) {
-                    println!("Error: {}", e);
+                    if !e.is_empty() {
+                        println!("Error: {}", e);
+                    }
                 }
             }
             None => println!("Unknown command: {}", cmd),